@@ -25,9 +25,10 @@ fn main() {
                 ],
             ),
         ])
+        .best_fn(|graph: Graph<f32>| graph.simplify())
         .fitness_fn(move |genotype: Graph<f32>| {
-            let mut reducer = GraphReducer::new(&genotype);
-            Score::from_f32(regression.error(|input| reducer.reduce(&input)))
+            let mut tape = genotype.compile();
+            Score::from_f32(regression.error(|input| tape.eval(input)))
         })
         .build();
 
@@ -44,6 +45,7 @@ fn display(result: &EngineContext<Node<f32>, Ops<f32>, Graph<f32>>) {
         println!("{:?}", node);
     }
     println!("{:?}", result.timer.elapsed());
+    println!("f(x) = {}", result.best.to_expressions(&["x"])[0]);
 
     let mut regression_accuracy = 0.0;
     let mut total = 0.0;
@@ -9,7 +9,7 @@ fn main() {
 
     let graph_codex = GraphCodex::from_shape(1, 1, &factory).set_nodes(|arc, _| arc.lstm(1, 1, 1));
 
-    let regression = Regression::new(get_sample_set(), ErrorFunction::MSE);
+    let series = get_series();
 
     let engine = GeneticEngine::from_codex(&graph_codex)
         .minimizing()
@@ -27,8 +27,8 @@ fn main() {
             ),
         ])
         .fitness_fn(move |genotype: Graph<f32>| {
-            let mut reducer = GraphReducer::new(&genotype);
-            Score::from_f32(regression.error(|input| reducer.reduce(&input)))
+            let mut tape = genotype.compile();
+            Score::from_f32(series.sequence_error(|sequence| tape.run_sequence(sequence)))
         })
         .build();
 
@@ -47,36 +47,21 @@ fn display(result: &EngineContext<Node<f32>, Ops<f32>, Graph<f32>>) {
 
     println!("{:?}", result.timer.elapsed());
 
+    let series = get_series();
     let mut reducer = GraphReducer::new(&result.best);
-    for sample in get_sample_set().get_samples().iter() {
-        let output = reducer.reduce(&sample.1);
+    let forecasts = series.forecasts(|sequence| reducer.run_sequence(sequence));
+    for (sample, forecast) in series.get_samples().iter().zip(forecasts.iter()) {
         println!(
             "{:?} -> epected: {:?}, actual: {:.3?}",
-            sample.1, sample.2, output
+            sample.1, sample.2, forecast
         );
     }
 }
 
-fn get_sample_set() -> SampleSet<f32> {
-    let inputs = vec![
-        vec![0.0],
-        vec![0.0],
-        vec![0.0],
-        vec![1.0],
-        vec![0.0],
-        vec![0.0],
-        vec![0.0],
-    ];
+fn get_series() -> TimeSeries<f32> {
+    let series = (0..12)
+        .map(|index| if index % 4 == 3 { 1.0 } else { 0.0 })
+        .collect::<Vec<f32>>();
 
-    let answers = vec![
-        vec![0.0],
-        vec![0.0],
-        vec![1.0],
-        vec![0.0],
-        vec![0.0],
-        vec![0.0],
-        vec![1.0],
-    ];
-
-    SampleSet::from_vecs(inputs, answers)
+    TimeSeries::univariate(series, ErrorFunction::MSE).lag(4)
 }
@@ -26,8 +26,8 @@ fn main() {
             ),
         ])
         .fitness_fn(move |genotype: Graph<f32>| {
-            let mut reducer = GraphReducer::new(&genotype);
-            Score::from_f32(regression.error(|input| reducer.reduce(&input)))
+            let mut tape = genotype.compile();
+            Score::from_f32(regression.error(|input| tape.eval(input)))
         })
         .build();
 
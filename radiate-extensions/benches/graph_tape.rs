@@ -0,0 +1,45 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use radiate_extensions::*;
+
+const ITERATIONS: usize = 20_000;
+
+fn time<F>(mut run: F) -> Duration
+where
+    F: FnMut(&[f32]) -> Vec<f32>,
+{
+    let inputs = (0..ITERATIONS)
+        .map(|i| vec![(i % 7) as f32 * 0.1; 8])
+        .collect::<Vec<Vec<f32>>>();
+
+    let start = Instant::now();
+    for input in inputs.iter() {
+        black_box(run(black_box(input)));
+    }
+
+    start.elapsed()
+}
+
+fn main() {
+    let factory = NodeFactory::<f32>::regression(8);
+
+    for sizes in [vec![8, 4], vec![8, 16, 4], vec![8, 32, 32, 4]] {
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).layered(&sizes);
+
+        let mut reducer = GraphReducer::new(&graph);
+        let mut tape = graph.compile();
+
+        let reduced = time(|input| reducer.reduce(input));
+        let taped = time(|input| tape.eval(input));
+
+        println!(
+            "layers {:?} ({} nodes): reducer {:?}, tape {:?}, speedup {:.2}x",
+            sizes,
+            graph.len(),
+            reduced / ITERATIONS as u32,
+            taped / ITERATIONS as u32,
+            reduced.as_secs_f64() / taped.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use radiate_rust::engines::alterers::mutators::mutate::Mutate;
+use radiate_rust::engines::genome::chromosome::Chromosome;
+use radiate_rust::Alterer;
+use rand::random;
+use rand::seq::SliceRandom;
+
+use crate::architects::node_collections::*;
+use crate::architects::schema::grid::Grid;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+
+pub struct CgpMutator<T>
+where
+    T: Clone + PartialEq + Default,
+{
+    pub factory: NodeFactory<T>,
+    pub grid: Grid,
+    pub function_rate: f32,
+    pub connection_rate: f32,
+}
+
+impl<T> CgpMutator<T>
+where
+    T: Clone + PartialEq + Default + 'static,
+{
+    pub fn new(
+        factory: NodeFactory<T>,
+        grid: Grid,
+        function_rate: f32,
+        connection_rate: f32,
+    ) -> Self {
+        Self {
+            factory,
+            grid,
+            function_rate,
+            connection_rate,
+        }
+    }
+
+    pub fn alterer(
+        factory: NodeFactory<T>,
+        grid: Grid,
+        function_rate: f32,
+        connection_rate: f32,
+    ) -> Alterer<Node<T>, Ops<T>> {
+        Alterer::Mutation(Box::new(CgpMutator::new(
+            factory,
+            grid,
+            function_rate,
+            connection_rate,
+        )))
+    }
+
+    pub fn mutate_function(&self, graph: &mut Graph<T>, index: usize) -> bool {
+        let arity = graph.get(index).unwrap().value.arity();
+        let new_node = self
+            .factory
+            .new_node_where(index, NodeType::Gate, |value| value.arity() == arity);
+
+        match new_node {
+            Some(node) => {
+                let current = graph.get_mut(index).unwrap();
+                current.value = node.value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn mutate_connection(&self, graph: &mut Graph<T>, index: usize) -> bool {
+        let mut random = rand::thread_rng();
+        let node = graph.get(index).unwrap();
+
+        let old_source = match node
+            .incoming
+            .iter()
+            .cloned()
+            .collect::<Vec<usize>>()
+            .choose(&mut random)
+        {
+            Some(source) => *source,
+            None => return false,
+        };
+
+        let candidates = self
+            .grid
+            .sources(index)
+            .into_iter()
+            .filter(|source| !node.incoming.contains(source))
+            .collect::<Vec<usize>>();
+
+        let new_source = match candidates.choose(&mut random) {
+            Some(source) => *source,
+            None => return false,
+        };
+
+        graph.detach(old_source, index);
+        graph.attach(new_source, index);
+
+        let orphaned = graph.get(old_source).unwrap().node_type == NodeType::Input
+            && graph.get(old_source).unwrap().outgoing.is_empty();
+
+        if orphaned {
+            graph.detach(new_source, index);
+            graph.attach(old_source, index);
+            return false;
+        }
+
+        true
+    }
+}
+
+impl<T> Mutate<Node<T>, Ops<T>> for CgpMutator<T>
+where
+    T: Clone + PartialEq + Default + 'static,
+{
+    fn mutate_rate(&self) -> f32 {
+        self.function_rate.max(self.connection_rate)
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<Node<T>, Ops<T>>, _: i32) -> i32 {
+        if chromosome.len() != self.grid.len() {
+            return 0;
+        }
+
+        let mut graph = Graph::from_nodes(chromosome.iter().cloned().collect::<Vec<Node<T>>>());
+        let mut count = 0;
+
+        for index in self.grid.gate_indices() {
+            if random::<f32>() < self.function_rate && self.mutate_function(&mut graph, index) {
+                count += 1;
+            }
+
+            if random::<f32>() < self.connection_rate && self.mutate_connection(&mut graph, index) {
+                count += 1;
+            }
+        }
+
+        for index in self.grid.output_indices() {
+            if random::<f32>() < self.connection_rate && self.mutate_connection(&mut graph, index) {
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            chromosome.set_genes(graph.into_iter().collect::<Vec<Node<T>>>());
+        }
+
+        count
+    }
+}
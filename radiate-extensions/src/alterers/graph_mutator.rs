@@ -1,4 +1,4 @@
-use rand::{random, seq::SliceRandom};
+use rand::{random, seq::SliceRandom, Rng};
 
 use radiate_rust::engines::alterers::Alter;
 use radiate_rust::engines::genome::*;
@@ -9,6 +9,8 @@ use crate::architects::node_collections::*;
 use crate::architects::schema::node_types::NodeType;
 use crate::operations::op::Ops;
 
+const MAX_REWIRE_ATTEMPTS: usize = 10;
+
 pub enum NodeMutate {
     Forward(NodeType, f32),
     Recurrent(NodeType, f32),
@@ -37,12 +39,34 @@ impl NodeMutate {
     }
 }
 
+pub enum StructureMutate {
+    RemoveNode(f32),
+    RemoveEdge(f32),
+    ToggleNode(f32),
+    ToggleEdge(f32),
+    Rewire(f32),
+}
+
+impl StructureMutate {
+    pub fn rate(&self) -> f32 {
+        match self {
+            StructureMutate::RemoveNode(rate) => *rate,
+            StructureMutate::RemoveEdge(rate) => *rate,
+            StructureMutate::ToggleNode(rate) => *rate,
+            StructureMutate::ToggleEdge(rate) => *rate,
+            StructureMutate::Rewire(rate) => *rate,
+        }
+    }
+}
+
 pub struct GraphMutator<T>
 where
     T: Clone + PartialEq + Default,
 {
     pub factory: NodeFactory<T>,
     pub mutations: Vec<NodeMutate>,
+    pub structures: Vec<StructureMutate>,
+    pub innovations: Innovations,
 }
 
 impl<T> GraphMutator<T>
@@ -50,7 +74,22 @@ where
     T: Clone + PartialEq + Default + 'static,
 {
     pub fn new(factory: NodeFactory<T>, mutations: Vec<NodeMutate>) -> Self {
-        Self { factory, mutations }
+        Self {
+            factory,
+            mutations,
+            structures: Vec::new(),
+            innovations: InnovationRegistry::shared(),
+        }
+    }
+
+    pub fn with_innovations(mut self, innovations: Innovations) -> Self {
+        self.innovations = innovations;
+        self
+    }
+
+    pub fn with_structures(mut self, structures: Vec<StructureMutate>) -> Self {
+        self.structures = structures;
+        self
     }
 
     pub fn alterer(
@@ -282,6 +321,226 @@ where
         return self.repair_insert(temp, collection.len(), source_node, target_node, true);
     }
 
+    #[inline]
+    pub fn remove_node(&self, collection: &[Node<T>]) -> Option<Vec<Node<T>>> {
+        let mut rng = rand::thread_rng();
+        let index = *collection
+            .iter()
+            .filter(|node| node.node_type != NodeType::Input && node.node_type != NodeType::Output)
+            .map(|node| node.index)
+            .collect::<Vec<usize>>()
+            .choose(&mut rng)?;
+
+        let node = &collection[index];
+        let sources = node
+            .incoming
+            .iter()
+            .filter(|source| **source != index)
+            .cloned()
+            .collect::<Vec<usize>>();
+        let targets = node
+            .outgoing
+            .iter()
+            .filter(|target| **target != index)
+            .cloned()
+            .collect::<Vec<usize>>();
+
+        let mut temp = Graph::from_nodes(collection.to_vec());
+        for target in targets.iter() {
+            temp.detach(index, *target);
+
+            let available = sources
+                .iter()
+                .filter(|source| {
+                    **source != *target && !temp.get(*target).unwrap().incoming.contains(source)
+                })
+                .cloned()
+                .collect::<Vec<usize>>();
+
+            match temp.get(*target).unwrap().node_type {
+                NodeType::Aggregate | NodeType::Output => {
+                    for source in available {
+                        temp.attach(source, *target);
+                    }
+                }
+                _ => {
+                    if let Some(source) = available.choose(&mut rng) {
+                        temp.attach(*source, *target);
+                    }
+                }
+            }
+        }
+
+        temp.remove_node(index);
+
+        let shift = |other: &usize| if *other > index { *other - 1 } else { *other };
+        let affected = sources
+            .iter()
+            .chain(targets.iter())
+            .map(shift)
+            .collect::<Vec<usize>>();
+
+        self.repair_structure(temp, affected)
+    }
+
+    #[inline]
+    pub fn remove_edge(&self, collection: &[Node<T>]) -> Option<Vec<Node<T>>> {
+        let mut edges = GraphMutator::edges(collection);
+        edges.shuffle(&mut rand::thread_rng());
+
+        for (source, target) in edges {
+            let mut source_node = collection[source].clone();
+            let mut target_node = collection[target].clone();
+
+            source_node.outgoing.remove(&target);
+            target_node.incoming.remove(&source);
+            if source == target {
+                source_node.incoming.remove(&source);
+            }
+
+            if source_node.is_valid() && (source == target || target_node.is_valid()) {
+                let mut temp = Graph::from_nodes(collection.to_vec());
+                temp.detach(source, target);
+
+                return self.repair_structure(temp, vec![source, target]);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    pub fn toggle(&self, collection: &[Node<T>], node_types: &[NodeType]) -> Option<Vec<Node<T>>> {
+        let index = *collection
+            .iter()
+            .filter(|node| node_types.contains(&node.node_type))
+            .map(|node| node.index)
+            .collect::<Vec<usize>>()
+            .choose(&mut rand::thread_rng())?;
+
+        let mut temp = collection.to_vec();
+        temp[index].enabled = !temp[index].enabled;
+
+        Some(temp)
+    }
+
+    #[inline]
+    pub fn toggle_edge(&self, collection: &[Node<T>]) -> Option<Vec<Node<T>>> {
+        let graph = Graph::from_nodes(collection.to_vec());
+        let index = *collection
+            .iter()
+            .filter(|node| node.node_type == NodeType::Weight)
+            .filter(|node| {
+                node.outgoing.iter().all(|target| {
+                    let target = &collection[*target];
+                    let sources = graph.sources(target.index);
+                    match target.node_type {
+                        NodeType::Aggregate | NodeType::Output if node.enabled => {
+                            sources.len() > 1 && sources.contains(&node.index)
+                        }
+                        NodeType::Aggregate | NodeType::Output => true,
+                        _ => false,
+                    }
+                })
+            })
+            .map(|node| node.index)
+            .collect::<Vec<usize>>()
+            .choose(&mut rand::thread_rng())?;
+
+        let mut temp = collection.to_vec();
+        temp[index].enabled = !temp[index].enabled;
+
+        Some(temp)
+    }
+
+    #[inline]
+    pub fn rewire(&self, collection: &[Node<T>]) -> Option<Vec<Node<T>>> {
+        let mut rng = rand::thread_rng();
+        let mut edges = GraphMutator::edges(collection)
+            .into_iter()
+            .filter(|(source, target)| {
+                source != target
+                    && !collection[*source].is_recurrent()
+                    && !collection[*target].is_recurrent()
+            })
+            .collect::<Vec<(usize, usize)>>();
+
+        edges.shuffle(&mut rng);
+
+        for (source, target) in edges.into_iter().take(MAX_REWIRE_ATTEMPTS) {
+            let mut temp = Graph::from_nodes(collection.to_vec());
+            temp.detach(source, target);
+
+            let rewire_source = random::<bool>();
+            let old_node = match rewire_source {
+                true => &collection[source],
+                false => &collection[target],
+            };
+
+            let candidates = collection
+                .iter()
+                .filter(|node| {
+                    node.index != source
+                        && node.index != target
+                        && (node.node_type == NodeType::Weight)
+                            == (old_node.node_type == NodeType::Weight)
+                })
+                .filter(|node| match rewire_source {
+                    true => {
+                        node.node_type != NodeType::Output
+                            && !node.outgoing.contains(&target)
+                            && !would_create_cycle(temp.get_nodes(), node.index, target)
+                    }
+                    false => {
+                        node.node_type != NodeType::Input
+                            && !node.incoming.contains(&source)
+                            && !would_create_cycle(temp.get_nodes(), source, node.index)
+                    }
+                })
+                .map(|node| node.index)
+                .collect::<Vec<usize>>();
+
+            let (new_source, new_target) = match candidates.choose(&mut rng) {
+                Some(index) if rewire_source => (*index, target),
+                Some(index) => (source, *index),
+                None => continue,
+            };
+
+            temp.attach(new_source, new_target);
+
+            if temp.is_valid() {
+                return self.repair_structure(temp, vec![source, target, new_source, new_target]);
+            }
+        }
+
+        None
+    }
+
+    fn edges(collection: &[Node<T>]) -> Vec<(usize, usize)> {
+        collection
+            .iter()
+            .flat_map(|node| {
+                node.outgoing
+                    .iter()
+                    .map(move |target| (node.index, *target))
+            })
+            .collect::<Vec<(usize, usize)>>()
+    }
+
+    #[inline]
+    fn repair_structure(&self, collection: Graph<T>, affected: Vec<usize>) -> Option<Vec<Node<T>>> {
+        if !collection.is_valid() {
+            return None;
+        }
+
+        Some(
+            collection
+                .set_cycles(affected)
+                .into_iter()
+                .collect::<Vec<Node<T>>>(),
+        )
+    }
+
     #[inline]
     fn repair_insert(
         &self,
@@ -324,10 +583,19 @@ where
     fn alter(&self, population: &mut Population<Node<T>, Ops<T>>, _: &Optimize, generation: i32) {
         let mut rng = rand::thread_rng();
 
+        let total = self.mutations.len() + self.structures.len();
+        if total == 0 {
+            return;
+        }
+
         for i in 0..population.len() {
-            let mutation = self.mutations.choose(&mut rng).unwrap();
+            let choice = rng.gen_range(0..total);
+            let rate = match self.mutations.get(choice) {
+                Some(mutation) => mutation.rate(),
+                None => self.structures[choice - self.mutations.len()].rate(),
+            };
 
-            if random::<f32>() > mutation.rate() {
+            if random::<f32>() > rate {
                 continue;
             }
 
@@ -335,19 +603,35 @@ where
             let chromosome_index = rand::random::<usize>() % genotype.len();
             let chromosome = genotype.get_chromosome(chromosome_index);
 
-            let mutated_graph = if mutation.is_recurrent() {
-                self.insert_recurrent_node(&chromosome.genes, &mutation.node_type())
-            } else {
-                self.insert_forward_node(&chromosome.genes, &mutation.node_type())
+            let mutated_graph = match self.mutations.get(choice) {
+                Some(NodeMutate::Forward(node_type, _)) => self
+                    .insert_forward_node(&chromosome.genes, node_type)
+                    .filter(|graph| graph.len() != chromosome.genes.len()),
+                Some(NodeMutate::Recurrent(node_type, _)) => self
+                    .insert_recurrent_node(&chromosome.genes, node_type)
+                    .filter(|graph| graph.len() != chromosome.genes.len()),
+                None => match &self.structures[choice - self.mutations.len()] {
+                    StructureMutate::RemoveNode(_) => self.remove_node(&chromosome.genes),
+                    StructureMutate::RemoveEdge(_) => self.remove_edge(&chromosome.genes),
+                    StructureMutate::ToggleNode(_) => self.toggle(
+                        &chromosome.genes,
+                        &[NodeType::Gate, NodeType::Aggregate, NodeType::Link],
+                    ),
+                    StructureMutate::ToggleEdge(_) => self.toggle_edge(&chromosome.genes),
+                    StructureMutate::Rewire(_) => self.rewire(&chromosome.genes),
+                },
             };
 
-            if let Some(mutated_graph) = mutated_graph {
+            if let Some(mut mutated_graph) = mutated_graph {
                 if !mutated_graph.iter().all(|node| node.is_valid()) {
                     continue;
                 }
 
-                if mutated_graph.len() == chromosome.genes.len() {
-                    continue;
+                {
+                    let mut innovations = self.innovations.write().unwrap();
+                    let first_new = chromosome.genes.len().min(mutated_graph.len());
+                    innovations.assign_nodes(&mut mutated_graph, first_new);
+                    innovations.assign_edges(&mutated_graph);
                 }
 
                 let mut copied_genotype = genotype.clone();
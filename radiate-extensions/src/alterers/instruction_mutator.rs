@@ -0,0 +1,80 @@
+use radiate_rust::engines::alterers::mutators::mutate::Mutate;
+use radiate_rust::engines::genome::chromosome::Chromosome;
+use radiate_rust::engines::genome::genes::gene::Gene;
+use radiate_rust::Alterer;
+use rand::seq::SliceRandom;
+use rand::{random, Rng};
+
+use crate::operations::op::Ops;
+use crate::programs::instruction::Instruction;
+
+pub struct InstructionMutator<T>
+where
+    T: Clone,
+{
+    pub rate: f32,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> InstructionMutator<T>
+where
+    T: Clone + 'static,
+{
+    pub fn new(rate: f32) -> Self {
+        Self {
+            rate,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn alterer(rate: f32) -> Alterer<Instruction<T>, Ops<T>> {
+        Alterer::Mutation(Box::new(InstructionMutator::<T>::new(rate)))
+    }
+}
+
+impl<T> Mutate<Instruction<T>, Ops<T>> for InstructionMutator<T>
+where
+    T: Clone,
+{
+    fn mutate_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn mutate_chromosome(
+        &self,
+        chromosome: &mut Chromosome<Instruction<T>, Ops<T>>,
+        _: i32,
+    ) -> i32 {
+        let mut count = 0;
+        for gene in chromosome.iter_mut() {
+            if random::<f32>() < self.rate {
+                *gene = self.mutate_gene(gene);
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    #[inline]
+    fn mutate_gene(&self, gene: &Instruction<T>) -> Instruction<T> {
+        let mut random = rand::thread_rng();
+
+        match random.gen_range(0..3) {
+            0 => {
+                let value = gene.op_set.choose(&mut random).unwrap().new_instance();
+                gene.from_allele(&value)
+            }
+            1 => gene.with_dst(gene.random_dst()),
+            _ => {
+                if gene.sources().is_empty() {
+                    return gene.from_allele(&gene.value().new_instance());
+                }
+
+                let index = random.gen_range(0..gene.sources().len());
+                gene.with_source(index, gene.random_source())
+            }
+        }
+    }
+}
@@ -1,9 +1,21 @@
+pub mod cgp_mutator;
 pub mod graph_crossover;
 pub mod graph_mutator;
+pub mod instruction_mutator;
+pub mod neat_crossover;
 pub mod node_crossover;
 pub mod op_mutator;
+pub mod program_crossover;
+pub mod subtree_crossover;
+pub mod tree_mutator;
 
+pub use cgp_mutator::*;
 pub use graph_crossover::*;
 pub use graph_mutator::*;
+pub use instruction_mutator::*;
+pub use neat_crossover::*;
 pub use node_crossover::*;
 pub use op_mutator::*;
+pub use program_crossover::*;
+pub use subtree_crossover::*;
+pub use tree_mutator::*;
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use radiate_rust::engines::alterers::Alter;
+use radiate_rust::engines::genome::*;
+use radiate_rust::engines::optimize::Optimize;
+use radiate_rust::Alterer;
+use rand::random;
+
+use crate::architects::node_collections::*;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+
+use super::GraphCrossover;
+
+const DISABLE_RATE: f32 = 0.75;
+
+pub struct NeatCrossover<T>
+where
+    T: Clone + PartialEq + Default,
+{
+    pub crossover_rate: f32,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> NeatCrossover<T>
+where
+    T: Clone + PartialEq + Default + 'static,
+{
+    pub fn new(crossover_rate: f32) -> Self {
+        Self {
+            crossover_rate,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn alterer(crossover_rate: f32) -> Alterer<Node<T>, Ops<T>> {
+        Alterer::Alterer(Box::new(NeatCrossover::<T>::new(crossover_rate)))
+    }
+
+    pub fn cross_nodes(&self, fitter: &[Node<T>], other: &[Node<T>], equal: bool) -> Vec<Node<T>> {
+        let other_genes = node_genes(other);
+
+        let mut child = fitter
+            .iter()
+            .map(|node| match other_genes.get(&node.innovation) {
+                Some(other_index) => {
+                    let other_node = &other[*other_index];
+                    let mut child = node.clone();
+
+                    if other_node.value.arity() == node.value.arity() && random::<bool>() {
+                        child.value = other_node.value.clone();
+                    }
+
+                    if !node.enabled || !other_node.enabled {
+                        child.enabled = random::<f32>() >= DISABLE_RATE;
+                    }
+
+                    child
+                }
+                None => node.clone(),
+            })
+            .collect::<Graph<T>>();
+
+        if equal {
+            let child_genes = node_genes(child.get_nodes());
+            let child_edges = edge_genes(child.get_nodes());
+
+            for (source, target) in edge_genes(other).difference(&child_edges) {
+                let (source, target) = match (child_genes.get(source), child_genes.get(target)) {
+                    (Some(source), Some(target)) => (*source, *target),
+                    _ => continue,
+                };
+
+                let accepts_input = matches!(
+                    child.nodes[target].node_type,
+                    NodeType::Aggregate | NodeType::Output
+                );
+
+                if accepts_input && can_connect(child.get_nodes(), source, target, false) {
+                    child.attach(source, target);
+                }
+            }
+        }
+
+        child.into_iter().collect::<Vec<Node<T>>>()
+    }
+
+    #[inline]
+    pub fn cross(
+        &self,
+        population: &Population<Node<T>, Ops<T>>,
+        indexes: &[usize],
+        optimize: &Optimize,
+        generation: i32,
+    ) -> Option<Phenotype<Node<T>, Ops<T>>> {
+        let parent_one = population.get(indexes[0]);
+        let parent_two = population.get(indexes[1]);
+
+        let (fitter, other, equal) = match (parent_one.score(), parent_two.score()) {
+            (Some(score_one), Some(score_two)) if optimize.is_better(score_two, score_one) => {
+                (parent_two, parent_one, false)
+            }
+            (Some(score_one), Some(score_two)) => {
+                let equal = !optimize.is_better(score_one, score_two);
+                (parent_one, parent_two, equal)
+            }
+            _ => (parent_one, parent_two, false),
+        };
+
+        let geno_fitter = fitter.genotype();
+        let geno_other = other.genotype();
+
+        let chromosomes = geno_fitter
+            .iter()
+            .zip(geno_other.iter())
+            .map(|(chromo_fitter, chromo_other)| {
+                Chromosome::from_genes(self.cross_nodes(
+                    &chromo_fitter.genes,
+                    &chromo_other.genes,
+                    equal,
+                ))
+            })
+            .collect::<Vec<Chromosome<Node<T>, Ops<T>>>>();
+
+        if chromosomes.len() != geno_fitter.len() {
+            return None;
+        }
+
+        Some(Phenotype::from_genotype(
+            Genotype { chromosomes },
+            generation,
+        ))
+    }
+}
+
+impl<T> Alter<Node<T>, Ops<T>> for NeatCrossover<T>
+where
+    T: Clone + PartialEq + Default + 'static,
+{
+    #[inline]
+    fn alter(
+        &self,
+        population: &mut Population<Node<T>, Ops<T>>,
+        optimize: &Optimize,
+        generation: i32,
+    ) {
+        optimize.sort(population);
+
+        let mut new_phenotypes = HashMap::new();
+        for index in 0..population.len() {
+            if random::<f32>() < self.crossover_rate && population.len() > 2 {
+                let parent_indexes = GraphCrossover::<T>::distinct_subset(population.len());
+
+                if let Some(phenotype) =
+                    self.cross(population, &parent_indexes, optimize, generation)
+                {
+                    new_phenotypes.insert(index, phenotype);
+                }
+            }
+        }
+
+        for (index, phenotype) in new_phenotypes.into_iter() {
+            population.set(index, phenotype);
+        }
+    }
+}
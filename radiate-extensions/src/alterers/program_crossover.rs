@@ -0,0 +1,88 @@
+use radiate_rust::engines::alterers::crossovers::crossover::Crossover;
+use radiate_rust::engines::genome::chromosome::Chromosome;
+use radiate_rust::Alterer;
+use rand::Rng;
+
+use crate::operations::op::Ops;
+use crate::programs::instruction::Instruction;
+
+const MAX_SEGMENT_ATTEMPTS: usize = 10;
+
+pub struct ProgramCrossover<T>
+where
+    T: Clone,
+{
+    pub rate: f32,
+    pub max_segment: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> ProgramCrossover<T>
+where
+    T: Clone + 'static,
+{
+    pub fn new(rate: f32, max_segment: usize) -> Self {
+        Self {
+            rate,
+            max_segment: std::cmp::max(max_segment, 1),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn alterer(rate: f32, max_segment: usize) -> Alterer<Instruction<T>, Ops<T>> {
+        Alterer::Crossover(Box::new(ProgramCrossover::<T>::new(rate, max_segment)))
+    }
+
+    fn random_segment(&self, length: usize) -> (usize, usize) {
+        let mut random = rand::thread_rng();
+        let start = random.gen_range(0..length);
+        let size = random.gen_range(1..=std::cmp::min(self.max_segment, length - start));
+
+        (start, start + size)
+    }
+}
+
+impl<T> Crossover<Instruction<T>, Ops<T>> for ProgramCrossover<T>
+where
+    T: Clone + 'static,
+{
+    fn cross_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn cross_chromosomes(
+        &self,
+        chrom_one: &mut Chromosome<Instruction<T>, Ops<T>>,
+        chrom_two: &mut Chromosome<Instruction<T>, Ops<T>>,
+    ) -> i32 {
+        if chrom_one.len() == 0 || chrom_two.len() == 0 {
+            return 0;
+        }
+
+        for _ in 0..MAX_SEGMENT_ATTEMPTS {
+            let (start_one, end_one) = self.random_segment(chrom_one.len());
+            let (start_two, end_two) = self.random_segment(chrom_two.len());
+
+            let new_length_one = chrom_one.len() - (end_one - start_one) + (end_two - start_two);
+            let new_length_two = chrom_two.len() - (end_two - start_two) + (end_one - start_one);
+
+            if !chrom_one.is_length_valid(new_length_one)
+                || !chrom_two.is_length_valid(new_length_two)
+            {
+                continue;
+            }
+
+            let segment_one = chrom_one.remove_genes(start_one, end_one);
+            let segment_two = chrom_two.remove_genes(start_two, end_two);
+            let cross_count = (segment_one.len() + segment_two.len()) as i32;
+
+            chrom_one.insert_genes(start_one, segment_two);
+            chrom_two.insert_genes(start_two, segment_one);
+
+            return cross_count;
+        }
+
+        0
+    }
+}
@@ -0,0 +1,91 @@
+use radiate_rust::engines::alterers::crossovers::crossover::Crossover;
+use radiate_rust::engines::genome::chromosome::Chromosome;
+use radiate_rust::Alterer;
+use rand::Rng;
+
+use crate::operations::op::Ops;
+use crate::trees::tree_node::TreeNode;
+
+const MAX_CROSS_ATTEMPTS: usize = 10;
+
+pub struct SubtreeCrossover<T>
+where
+    T: Clone,
+{
+    pub rate: f32,
+    pub max_depth: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> SubtreeCrossover<T>
+where
+    T: Clone + 'static,
+{
+    pub fn new(rate: f32, max_depth: usize) -> Self {
+        Self {
+            rate,
+            max_depth,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn alterer(rate: f32, max_depth: usize) -> Alterer<TreeNode<T>, Ops<T>> {
+        Alterer::Crossover(Box::new(SubtreeCrossover::<T>::new(rate, max_depth)))
+    }
+
+    pub fn cross_trees(
+        &self,
+        tree_one: &TreeNode<T>,
+        tree_two: &TreeNode<T>,
+    ) -> Option<(TreeNode<T>, TreeNode<T>)> {
+        for _ in 0..MAX_CROSS_ATTEMPTS {
+            let index_one = tree_one.random_index();
+            let index_two = tree_two.random_index();
+
+            let subtree_one = tree_one.get(index_one).unwrap().clone();
+            let subtree_two = tree_two.get(index_two).unwrap().clone();
+
+            let child_one = tree_one.replace(index_one, subtree_two);
+            let child_two = tree_two.replace(index_two, subtree_one);
+
+            if child_one.depth() <= self.max_depth && child_two.depth() <= self.max_depth {
+                return Some((child_one, child_two));
+            }
+        }
+
+        None
+    }
+}
+
+impl<T> Crossover<TreeNode<T>, Ops<T>> for SubtreeCrossover<T>
+where
+    T: Clone + 'static,
+{
+    fn cross_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn cross_chromosomes(
+        &self,
+        chrom_one: &mut Chromosome<TreeNode<T>, Ops<T>>,
+        chrom_two: &mut Chromosome<TreeNode<T>, Ops<T>>,
+    ) -> i32 {
+        let length = std::cmp::min(chrom_one.len(), chrom_two.len());
+        if length == 0 {
+            return 0;
+        }
+
+        let index = rand::thread_rng().gen_range(0..length);
+        let crossed = self.cross_trees(chrom_one.get_gene(index), chrom_two.get_gene(index));
+
+        match crossed {
+            Some((child_one, child_two)) => {
+                chrom_one.set_gene(index, child_one);
+                chrom_two.set_gene(index, child_two);
+                1
+            }
+            None => 0,
+        }
+    }
+}
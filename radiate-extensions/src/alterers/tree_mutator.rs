@@ -0,0 +1,151 @@
+use radiate_rust::engines::alterers::mutators::mutate::Mutate;
+use radiate_rust::engines::genome::chromosome::Chromosome;
+use radiate_rust::Alterer;
+use rand::seq::SliceRandom;
+use rand::{random, Rng};
+
+use crate::architects::node_collections::node_factory::NodeFactory;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+use crate::trees::tree_node::TreeNode;
+
+pub enum TreeMutate {
+    Subtree(f32),
+    Point(f32),
+    Hoist(f32),
+    Shrink(f32),
+}
+
+impl TreeMutate {
+    pub fn rate(&self) -> f32 {
+        match self {
+            TreeMutate::Subtree(rate) => *rate,
+            TreeMutate::Point(rate) => *rate,
+            TreeMutate::Hoist(rate) => *rate,
+            TreeMutate::Shrink(rate) => *rate,
+        }
+    }
+}
+
+pub struct TreeMutator<T>
+where
+    T: Clone + PartialEq + Default,
+{
+    pub factory: NodeFactory<T>,
+    pub max_depth: usize,
+    pub mutations: Vec<TreeMutate>,
+}
+
+impl<T> TreeMutator<T>
+where
+    T: Clone + PartialEq + Default + 'static,
+{
+    pub fn new(factory: NodeFactory<T>, max_depth: usize, mutations: Vec<TreeMutate>) -> Self {
+        Self {
+            factory,
+            max_depth,
+            mutations,
+        }
+    }
+
+    pub fn alterer(
+        factory: NodeFactory<T>,
+        max_depth: usize,
+        mutations: Vec<TreeMutate>,
+    ) -> Alterer<TreeNode<T>, Ops<T>> {
+        Alterer::Mutation(Box::new(TreeMutator::new(factory, max_depth, mutations)))
+    }
+
+    pub fn subtree(&self, tree: &TreeNode<T>) -> TreeNode<T> {
+        let index = tree.random_index();
+        let level = tree.level(index).unwrap();
+        let remaining = self.max_depth.saturating_sub(level);
+        let depth = rand::thread_rng().gen_range(0..=remaining);
+
+        tree.replace(index, TreeNode::grow(&self.factory, depth, false))
+    }
+
+    pub fn point(&self, tree: &TreeNode<T>) -> TreeNode<T> {
+        let index = tree.random_index();
+        let node = tree.get(index).unwrap();
+        let arity = node.children.len();
+
+        let node_type = match node.is_leaf() {
+            true => NodeType::Input,
+            false => NodeType::Gate,
+        };
+
+        let candidates = self
+            .factory
+            .node_values
+            .get(&node_type)
+            .map(|values| {
+                values
+                    .iter()
+                    .filter(|value| node.is_leaf() || value.arity() as usize == arity)
+                    .collect::<Vec<&Ops<T>>>()
+            })
+            .unwrap_or_default();
+
+        match candidates.choose(&mut rand::thread_rng()) {
+            Some(value) => tree.replace(
+                index,
+                TreeNode::new(value.new_instance(), node.children.clone()),
+            ),
+            None => tree.clone(),
+        }
+    }
+
+    pub fn hoist(&self, tree: &TreeNode<T>) -> TreeNode<T> {
+        tree.get(tree.random_index()).unwrap().clone()
+    }
+
+    pub fn shrink(&self, tree: &TreeNode<T>) -> TreeNode<T> {
+        let internal = (0..tree.size())
+            .filter(|index| !tree.get(*index).unwrap().is_leaf())
+            .collect::<Vec<usize>>();
+
+        match internal.choose(&mut rand::thread_rng()) {
+            Some(index) => tree.replace(*index, TreeNode::random_terminal(&self.factory)),
+            None => tree.clone(),
+        }
+    }
+}
+
+impl<T> Mutate<TreeNode<T>, Ops<T>> for TreeMutator<T>
+where
+    T: Clone + PartialEq + Default + 'static,
+{
+    fn mutate_rate(&self) -> f32 {
+        self.mutations
+            .iter()
+            .map(|mutation| mutation.rate())
+            .fold(0_f32, f32::max)
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<TreeNode<T>, Ops<T>>, _: i32) -> i32 {
+        let mut count = 0;
+        for gene in chromosome.iter_mut() {
+            for mutation in self.mutations.iter() {
+                if random::<f32>() >= mutation.rate() {
+                    continue;
+                }
+
+                let mutated = match mutation {
+                    TreeMutate::Subtree(_) => self.subtree(gene),
+                    TreeMutate::Point(_) => self.point(gene),
+                    TreeMutate::Hoist(_) => self.hoist(gene),
+                    TreeMutate::Shrink(_) => self.shrink(gene),
+                };
+
+                if mutated.depth() <= self.max_depth {
+                    *gene = mutated;
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
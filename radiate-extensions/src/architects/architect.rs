@@ -2,7 +2,13 @@ use crate::architects::node_collection_builder::NodeCollectionBuilder;
 use crate::architects::node_collections::node::Node;
 use crate::architects::node_collections::node_collection::NodeCollection;
 use crate::architects::node_collections::node_factory::NodeFactory;
+use crate::architects::schema::grid::Grid;
+use crate::architects::schema::layers::Layers;
 use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::{self, Ops};
+
+use num_traits::Float;
+use rand::seq::SliceRandom;
 
 use super::Graph;
 
@@ -65,6 +71,178 @@ where
             .collect::<Vec<Node<T>>>()
     }
 
+    pub fn activation_collection(&self, node_type: NodeType, size: usize, name: &str) -> C {
+        let value = [node_type, NodeType::Aggregate]
+            .iter()
+            .filter_map(|node_type| self.node_factory.node_values.get(node_type))
+            .flatten()
+            .find(|value| value.name() == name)
+            .unwrap_or_else(|| {
+                panic!(
+                    "The node factory has no '{}' value to use as an activation.",
+                    name
+                )
+            });
+
+        C::from_nodes(
+            (0..size)
+                .map(|index| {
+                    Node::new(index, node_type, value.new_instance()).set_arity(value.arity())
+                })
+                .collect::<Vec<Node<T>>>(),
+        )
+    }
+
+    pub fn softmax_output(&self, size: usize) -> C
+    where
+        T: Float,
+    {
+        C::from_nodes(
+            (0..size)
+                .map(|index| Node::new(index, NodeType::Output, op::softmax(index)))
+                .collect::<Vec<Node<T>>>(),
+        )
+    }
+
+    pub fn cgp(&self, grid: &Grid) -> Graph<T> {
+        let mut random = rand::thread_rng();
+        let mut nodes = self.new_nodes(NodeType::Input, grid.input_size);
+
+        for index in grid.gate_indices() {
+            let max_arity = grid.sources(index).len();
+            let node = self
+                .node_factory
+                .new_node_where(index, NodeType::Gate, |value| {
+                    value.arity() as usize <= max_arity
+                })
+                .expect("The node factory has no gate values that fit the grid.");
+
+            nodes.push(node);
+        }
+
+        for index in grid.output_indices() {
+            nodes.push(self.node_factory.new_node(index, NodeType::Output));
+        }
+
+        let mut graph = Graph::from_nodes(nodes);
+        let slots = grid
+            .gate_indices()
+            .flat_map(|index| {
+                let arity = graph.get(index).unwrap().value.arity() as usize;
+                std::iter::repeat_n(index, arity)
+            })
+            .collect::<Vec<usize>>();
+
+        for (input, target) in (0..grid.input_size).zip(slots.iter()) {
+            graph.attach(input, *target);
+        }
+
+        for index in grid.gate_indices() {
+            let arity = graph.get(index).unwrap().value.arity() as usize;
+            let mut sources = grid
+                .sources(index)
+                .into_iter()
+                .filter(|source| !graph.get(index).unwrap().incoming.contains(source))
+                .collect::<Vec<usize>>();
+
+            sources.shuffle(&mut random);
+
+            while graph.get(index).unwrap().incoming.len() < arity {
+                match sources.pop() {
+                    Some(source) => graph.attach(source, index),
+                    None => break,
+                };
+            }
+        }
+
+        for index in grid.output_indices() {
+            if let Some(source) = grid.sources(index).choose(&mut random) {
+                graph.attach(*source, index);
+            }
+        }
+
+        let outputs = grid.output_indices().collect::<Vec<usize>>();
+        for input in 0..grid.input_size {
+            if graph.get(input).unwrap().outgoing.is_empty() {
+                if let Some(output) = outputs.choose(&mut random) {
+                    graph.attach(input, *output);
+                }
+            }
+        }
+
+        graph
+    }
+
+    pub fn layered(&self, sizes: &[usize]) -> Graph<T> {
+        self.layered_with(&Layers::new(sizes))
+    }
+
+    pub fn layered_with(&self, layers: &Layers<T>) -> Graph<T> {
+        Architect::<Graph<T>, T>::new(self.node_factory).build(|arc, builder| {
+            let collections = (0..layers.len())
+                .map(|layer| {
+                    let node_type = match layer {
+                        0 => NodeType::Input,
+                        _ if layer == layers.len() - 1 => NodeType::Output,
+                        _ => NodeType::Aggregate,
+                    };
+
+                    match &layers.activations[layer] {
+                        Some(name) => {
+                            arc.activation_collection(node_type, layers.sizes[layer], name)
+                        }
+                        None => arc.new_collection(node_type, layers.sizes[layer]),
+                    }
+                })
+                .collect::<Vec<Graph<T>>>();
+
+            // Single-node views keep their layer's node ids, so the builder wires them as the same nodes.
+            let neurons = collections
+                .iter()
+                .map(|layer| {
+                    layer
+                        .iter()
+                        .map(|node| Graph::from_nodes(vec![node.clone()]))
+                        .collect::<Vec<Graph<T>>>()
+                })
+                .collect::<Vec<Vec<Graph<T>>>>();
+
+            let bias = layers.bias.as_ref().map(|value| {
+                Graph::from_nodes(vec![Node::new(
+                    0,
+                    NodeType::Gate,
+                    Ops::Value(value.clone()),
+                )
+                .set_arity(0)])
+            });
+
+            let mut sources = layers
+                .connections()
+                .into_iter()
+                .map(|(from, to)| (&collections[from], to))
+                .collect::<Vec<(&Graph<T>, usize)>>();
+
+            if let Some(bias) = &bias {
+                sources.extend((1..layers.len()).map(|to| (bias, to)));
+            }
+
+            let connections = sources
+                .into_iter()
+                .flat_map(|(source, to)| neurons[to].iter().map(move |neuron| (source, neuron)))
+                .map(|(source, neuron)| (source, arc.weight(source.len()), neuron))
+                .collect::<Vec<(&Graph<T>, Graph<T>, &Graph<T>)>>();
+
+            connections
+                .iter()
+                .fold(builder, |builder, (source, weights, neuron)| {
+                    builder
+                        .one_to_one(source, weights)
+                        .all_to_all(weights, neuron)
+                })
+                .build()
+        })
+    }
+
     pub fn acyclic(&self, input_size: usize, output_size: usize) -> Graph<T> {
         Architect::<Graph<T>, T>::new(self.node_factory).build(|arc, builder| {
             builder
@@ -7,6 +7,8 @@ pub use architect::Architect;
 pub use node_collection_builder::NodeCollectionBuilder;
 
 pub use schema::direction::Direction;
+pub use schema::grid::Grid;
+pub use schema::layers::Layers;
 pub use schema::node_types::NodeType;
 
 pub use node_collections::*;
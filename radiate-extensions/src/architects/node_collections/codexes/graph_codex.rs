@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use radiate_rust::engines::codexes::Codex;
 use radiate_rust::engines::genome::chromosome::Chromosome;
 use radiate_rust::engines::genome::genes::gene::Gene;
@@ -14,6 +16,8 @@ where
     pub output_size: usize,
     pub factory: &'a NodeFactory<T>,
     pub nodes: Vec<Node<T>>,
+    pub grid: Option<Grid>,
+    pub innovations: Innovations,
 }
 
 impl<'a, T> GraphCodex<'a, T>
@@ -34,6 +38,27 @@ where
         GraphCodex::from_nodes(nodes, factory)
     }
 
+    pub fn from_grid(grid: Grid, factory: &'a NodeFactory<T>) -> Self {
+        let nodes = Architect::<Graph<T>, T>::new(factory)
+            .cgp(&grid)
+            .into_iter()
+            .collect::<Vec<Node<T>>>();
+
+        GraphCodex {
+            grid: Some(grid),
+            ..GraphCodex::from_nodes(nodes, factory)
+        }
+    }
+
+    pub fn from_layers(layers: &Layers<T>, factory: &'a NodeFactory<T>) -> Self {
+        let nodes = Architect::<Graph<T>, T>::new(factory)
+            .layered_with(layers)
+            .into_iter()
+            .collect::<Vec<Node<T>>>();
+
+        GraphCodex::from_nodes(nodes, factory)
+    }
+
     pub fn from_nodes(nodes: Vec<Node<T>>, factory: &'a NodeFactory<T>) -> Self {
         GraphCodex {
             input_size: nodes
@@ -46,9 +71,20 @@ where
                 .count(),
             factory,
             nodes,
+            grid: None,
+            innovations: InnovationRegistry::shared(),
         }
     }
 
+    pub fn with_innovations(mut self, innovations: Innovations) -> Self {
+        self.innovations = innovations;
+        self
+    }
+
+    pub fn innovations(&self) -> Innovations {
+        Arc::clone(&self.innovations)
+    }
+
     pub fn set_nodes<F>(mut self, node_fn: F) -> Self
     where
         F: Fn(&Architect<Graph<T>, T>, NodeCollectionBuilder<Graph<T>, T>) -> Graph<T>,
@@ -68,6 +104,7 @@ where
             .iter()
             .filter(|node| node.node_type == NodeType::Output)
             .count();
+        self.grid = None;
         self
     }
 }
@@ -77,6 +114,17 @@ where
     T: Clone + PartialEq + Default,
 {
     fn encode(&self) -> Genotype<Node<T>, Ops<T>> {
+        if let Some(grid) = &self.grid {
+            return Genotype {
+                chromosomes: vec![Chromosome::from_genes(
+                    Architect::<Graph<T>, T>::new(self.factory)
+                        .cgp(grid)
+                        .into_iter()
+                        .collect::<Vec<Node<T>>>(),
+                )],
+            };
+        }
+
         Genotype {
             chromosomes: vec![Chromosome::from_genes(
                 self.nodes
@@ -118,6 +118,24 @@ where
         Node::new(index, node_type, Ops::default())
     }
 
+    pub fn new_node_where<F>(&self, index: usize, node_type: NodeType, filter: F) -> Option<Node<T>>
+    where
+        F: Fn(&Ops<T>) -> bool,
+    {
+        let mut rng = rand::thread_rng();
+        let values = self
+            .node_values
+            .get(&node_type)?
+            .iter()
+            .filter(|value| filter(value))
+            .collect::<Vec<&Ops<T>>>();
+
+        values.choose(&mut rng).map(|value| {
+            let arity = value.arity();
+            Node::new(index, node_type, value.new_instance()).set_arity(arity)
+        })
+    }
+
     pub fn regression(input_size: usize) -> NodeFactory<f32> {
         NodeFactory::new()
             .inputs(
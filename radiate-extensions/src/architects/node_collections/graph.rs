@@ -1,9 +1,42 @@
+use std::collections::HashSet;
+
 use radiate_rust::engines::genome::genes::gene::Valid;
 
-use crate::{architects::node_collections::node::Node, node_collection, Direction};
+use crate::architects::node_collections::tracer::Tracer;
+use crate::operations::op::Ops;
+use crate::{architects::node_collections::node::Node, node_collection, Direction, NodeType};
 
 use super::{super::node_collection::NodeCollection, GraphIterator};
 
+const IDENTITY_OPS: [&str; 2] = ["linear", "sum"];
+const CLAMPED_OPS: [&str; 25] = [
+    "+",
+    "-",
+    "*",
+    "/",
+    "sum",
+    "prod",
+    "neg",
+    "pow",
+    "sqrt",
+    "abs",
+    "exp",
+    "log",
+    "sin",
+    "cos",
+    "tan",
+    "ceil",
+    "floor",
+    "sigmoid",
+    "tanh",
+    "relu",
+    "l_relu",
+    "linear",
+    "mish",
+    "soft_plus",
+    "w",
+];
+
 pub struct Graph<T>
 where
     T: Clone + PartialEq,
@@ -22,6 +55,216 @@ where
     pub fn topological_iter(&self) -> impl Iterator<Item = &Node<T>> {
         GraphIterator::new(&self)
     }
+
+    pub fn remove_node(&mut self, index: usize) -> Node<T> {
+        let shift = |other: &usize| if *other > index { *other - 1 } else { *other };
+        let removed = self.nodes.remove(index);
+
+        for node in self.nodes.iter_mut() {
+            node.index = shift(&node.index);
+            node.incoming = node
+                .incoming
+                .iter()
+                .filter(|other| **other != index)
+                .map(shift)
+                .collect::<HashSet<usize>>();
+            node.outgoing = node
+                .outgoing
+                .iter()
+                .filter(|other| **other != index)
+                .map(shift)
+                .collect::<HashSet<usize>>();
+        }
+
+        removed
+    }
+
+    pub fn sources(&self, index: usize) -> Vec<usize> {
+        let node = &self.nodes[index];
+        let mut sources = node.incoming.iter().cloned().collect::<Vec<usize>>();
+        sources.sort();
+
+        if node.node_type != NodeType::Aggregate && node.node_type != NodeType::Output {
+            return sources;
+        }
+
+        let enabled = sources
+            .iter()
+            .filter(|source| {
+                let source = &self.nodes[**source];
+                source.enabled || source.node_type != NodeType::Weight
+            })
+            .cloned()
+            .collect::<Vec<usize>>();
+
+        match enabled.is_empty() {
+            true => sources,
+            false => enabled,
+        }
+    }
+
+    pub fn active_nodes(&self) -> HashSet<usize> {
+        let mut active = HashSet::new();
+        let mut pending = self
+            .nodes
+            .iter()
+            .filter(|node| node.node_type == NodeType::Output)
+            .map(|node| node.index)
+            .collect::<Vec<usize>>();
+
+        while let Some(index) = pending.pop() {
+            if !active.insert(index) {
+                continue;
+            }
+
+            pending.extend(self.nodes[index].incoming.iter().cloned());
+        }
+
+        active
+    }
+
+    pub fn simplify(&self) -> Graph<T> {
+        let mut graph = self.clone();
+
+        while graph.fold_constants() | graph.collapse_identities() {}
+
+        let active = graph.active_nodes();
+        let removable = graph
+            .nodes
+            .iter()
+            .filter(|node| {
+                !active.contains(&node.index)
+                    && node.node_type != NodeType::Input
+                    && node.node_type != NodeType::Output
+            })
+            .map(|node| node.index)
+            .collect::<Vec<usize>>();
+
+        for index in removable.into_iter().rev() {
+            graph.remove_node(index);
+        }
+
+        graph
+    }
+
+    fn fold_constants(&mut self) -> bool {
+        let mut changed = false;
+
+        for index in 0..self.nodes.len() {
+            let node = &self.nodes[index];
+            if node.node_type == NodeType::Input || node.is_recurrent() || Self::is_constant(node) {
+                continue;
+            }
+
+            let sources = self.sources(index);
+
+            let foldable = !node.enabled
+                || (!sources.is_empty()
+                    && sources.iter().all(|source| {
+                        Self::is_constant(&self.nodes[*source])
+                            && !self.nodes[*source].is_recurrent()
+                    }));
+
+            if !foldable {
+                continue;
+            }
+
+            let mut tracer = Tracer::new(sources.len());
+            for source in sources.iter() {
+                tracer.add_input(Self::constant_value(&self.nodes[*source]));
+            }
+            tracer.eval(node);
+
+            for source in sources {
+                self.detach(source, index);
+            }
+
+            let node = &mut self.nodes[index];
+            node.value = Ops::Value(tracer.result.unwrap_or_default());
+            node.arity = Some(0);
+            node.enabled = true;
+            if node.node_type != NodeType::Output {
+                node.node_type = NodeType::Gate;
+            }
+
+            changed = true;
+        }
+
+        changed
+    }
+
+    fn collapse_identities(&mut self) -> bool {
+        let mut changed = false;
+
+        for index in 0..self.nodes.len() {
+            let node = &self.nodes[index];
+            let is_identity =
+                matches!(node.value, Ops::Fn(name, _, _) if IDENTITY_OPS.contains(&name));
+
+            if !is_identity
+                || !node.enabled
+                || node.incoming.len() != 1
+                || node.is_recurrent()
+                || node.node_type == NodeType::Input
+                || node.node_type == NodeType::Output
+            {
+                continue;
+            }
+
+            // sum and linear clamp their single input, so they are only an identity
+            // when the source has already clamped its own output.
+            let source = *node.incoming.iter().next().unwrap();
+            if !Self::is_clamped(&self.nodes[source]) {
+                continue;
+            }
+
+            // Targets read their inputs in index order, so rewiring is only safe when
+            // no other input of the target sits between the source and this node.
+            let (low, high) = (source.min(index), source.max(index));
+            let can_bypass = node.outgoing.iter().all(|target| {
+                let target = &self.nodes[*target];
+                !target.incoming.contains(&source)
+                    && !target
+                        .incoming
+                        .iter()
+                        .any(|other| *other > low && *other < high)
+            });
+
+            if !can_bypass || self.nodes[source].is_recurrent() {
+                continue;
+            }
+
+            for target in self.nodes[index].outgoing.clone() {
+                self.detach(index, target).attach(source, target);
+            }
+            self.detach(source, index);
+
+            changed = true;
+        }
+
+        changed
+    }
+
+    fn is_clamped(node: &Node<T>) -> bool {
+        match &node.value {
+            Ops::Fn(name, _, _) | Ops::MutableConst(name, _, _, _, _) => CLAMPED_OPS.contains(name),
+            _ => false,
+        }
+    }
+
+    fn is_constant(node: &Node<T>) -> bool {
+        node.node_type != NodeType::Input
+            && node.enabled
+            && node.incoming.is_empty()
+            && matches!(node.value, Ops::Const(_, _) | Ops::Value(_))
+    }
+
+    fn constant_value(node: &Node<T>) -> T {
+        match &node.value {
+            Ops::Const(_, value) | Ops::Value(value) => value.clone(),
+            _ => T::default(),
+        }
+    }
 }
 
 impl<T> NodeCollection<Graph<T>, T> for Graph<T>
@@ -0,0 +1,150 @@
+use std::path::Path;
+
+use crate::architects::node_collections::graph::Graph;
+use crate::architects::node_collections::node::Node;
+use crate::architects::schema::direction::Direction;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+
+impl<T> Graph<T>
+where
+    T: Clone + PartialEq + Default + std::fmt::Debug,
+{
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec![String::from("digraph G {"), String::from("    rankdir=LR;")];
+
+        for node in self.nodes.iter() {
+            let mut attributes = vec![
+                format!(
+                    "label=\"{}\"",
+                    node_label(node).replace('"', "\\\"").replace('\n', "\\n")
+                ),
+                format!("shape={}", dot_shape(&node.node_type)),
+            ];
+
+            if !node.enabled {
+                attributes.push(String::from("style=\"filled,dashed\""));
+                attributes.push(String::from("fillcolor=lightgray"));
+            } else if node.is_recurrent() {
+                attributes.push(String::from("style=filled"));
+                attributes.push(String::from("fillcolor=lightblue"));
+            }
+
+            lines.push(format!("    n{} [{}];", node.index, attributes.join(", ")));
+        }
+
+        for (source, target) in self.edges() {
+            match self.is_recurrent_edge(source, target) {
+                true => lines.push(format!(
+                    "    n{} -> n{} [style=dashed, color=blue, constraint=false];",
+                    source, target
+                )),
+                false => lines.push(format!("    n{} -> n{};", source, target)),
+            }
+        }
+
+        lines.push(String::from("}"));
+        lines.join("\n")
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        let mut lines = vec![String::from("flowchart LR")];
+
+        for node in self.nodes.iter() {
+            let label = node_label(node)
+                .replace('"', "#quot;")
+                .replace('\n', "<br/>");
+            let (open, close) = mermaid_shape(&node.node_type);
+            lines.push(format!("    n{}{}\"{}\"{}", node.index, open, label, close));
+        }
+
+        for (source, target) in self.edges() {
+            match self.is_recurrent_edge(source, target) {
+                true => lines.push(format!("    n{} -.-> n{}", source, target)),
+                false => lines.push(format!("    n{} --> n{}", source, target)),
+            }
+        }
+
+        lines.push(String::from(
+            "    classDef disabled fill:#d3d3d3,stroke-dasharray:5 5;",
+        ));
+        lines.push(String::from("    classDef recurrent fill:#add8e6;"));
+
+        for node in self.nodes.iter() {
+            if !node.enabled {
+                lines.push(format!("    class n{} disabled;", node.index));
+            } else if node.is_recurrent() {
+                lines.push(format!("    class n{} recurrent;", node.index));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    pub fn write_dot(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_dot())
+    }
+
+    pub fn write_mermaid(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_mermaid())
+    }
+
+    fn edges(&self) -> Vec<(usize, usize)> {
+        let mut edges = self
+            .nodes
+            .iter()
+            .flat_map(|node| {
+                node.outgoing
+                    .iter()
+                    .map(move |target| (node.index, *target))
+            })
+            .collect::<Vec<(usize, usize)>>();
+
+        edges.sort();
+        edges
+    }
+
+    fn is_recurrent_edge(&self, source: usize, target: usize) -> bool {
+        source == target
+            || (self.nodes[source].direction == Direction::Backward
+                && self.nodes[target].direction == Direction::Backward)
+    }
+}
+
+fn node_label<T>(node: &Node<T>) -> String
+where
+    T: Clone + PartialEq + std::fmt::Debug,
+{
+    let value = match &node.value {
+        Ops::Fn(name, _, _) => name.to_string(),
+        Ops::Indexed(name, index, _) => format!("{}({})", name, index),
+        Ops::Value(value) => format!("{:?}", value),
+        Ops::Var(name, _) => name.clone(),
+        Ops::Const(name, value) => format!("{}({:?})", name, value),
+        Ops::MutableConst(name, _, value, _, _) => format!("{}({:.2?})", name, value),
+    };
+
+    format!("{} {:?}\n{}", node.index, node.node_type, value)
+}
+
+fn dot_shape(node_type: &NodeType) -> &'static str {
+    match node_type {
+        NodeType::Input => "box",
+        NodeType::Output => "doublecircle",
+        NodeType::Gate => "ellipse",
+        NodeType::Aggregate => "hexagon",
+        NodeType::Weight => "diamond",
+        NodeType::Link => "circle",
+    }
+}
+
+fn mermaid_shape(node_type: &NodeType) -> (&'static str, &'static str) {
+    match node_type {
+        NodeType::Input => ("[", "]"),
+        NodeType::Output => ("((", "))"),
+        NodeType::Gate => ("(", ")"),
+        NodeType::Aggregate => ("{{", "}}"),
+        NodeType::Weight => ("{", "}"),
+        NodeType::Link => ("([", "])"),
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+
+use crate::architects::node_collections::graph::Graph;
+use crate::architects::node_collections::node::Node;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+
+const ACTIVATIONS: [&str; 6] = ["sigmoid", "relu", "tanh", "mish", "l_relu", "soft_plus"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpressionFormat {
+    Infix,
+    Latex,
+    Rust,
+}
+
+impl<T> Graph<T>
+where
+    T: Clone + PartialEq + Default + std::fmt::Debug,
+{
+    pub fn to_expressions(&self, input_names: &[&str]) -> Vec<String> {
+        self.render(input_names, ExpressionFormat::Infix)
+    }
+
+    pub fn to_latex(&self, input_names: &[&str]) -> Vec<String> {
+        self.render(input_names, ExpressionFormat::Latex)
+    }
+
+    pub fn to_rust(&self, input_names: &[&str], fn_name: &str) -> String {
+        let type_name = std::any::type_name::<T>();
+        let mut params = self
+            .nodes
+            .iter()
+            .filter(|node| node.node_type == NodeType::Input)
+            .map(|node| format!("{}: {}", input_name(node, input_names), type_name))
+            .collect::<Vec<String>>();
+
+        let outputs = self.render(input_names, ExpressionFormat::Rust);
+        if outputs.iter().any(|output| output.contains("prev[")) {
+            params.push(format!("prev: &[{}]", type_name));
+        }
+
+        format!(
+            "pub fn {}({}) -> [{}; {}] {{\n    [\n{}\n    ]\n}}\n",
+            fn_name,
+            params.join(", "),
+            type_name,
+            outputs.len(),
+            outputs
+                .iter()
+                .map(|output| format!("        {},", output))
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+
+    pub fn render(&self, input_names: &[&str], format: ExpressionFormat) -> Vec<String> {
+        self.nodes
+            .iter()
+            .filter(|node| node.node_type == NodeType::Output)
+            .map(|node| {
+                let mut path = HashSet::new();
+                self.render_node(node.index, input_names, format, &mut path)
+            })
+            .collect::<Vec<String>>()
+    }
+
+    fn render_node(
+        &self,
+        index: usize,
+        input_names: &[&str],
+        format: ExpressionFormat,
+        path: &mut HashSet<usize>,
+    ) -> String {
+        let node = &self.nodes[index];
+
+        if node.node_type == NodeType::Input {
+            return input_name(node, input_names);
+        }
+
+        if !node.enabled {
+            return literal(&T::default(), format);
+        }
+
+        path.insert(index);
+
+        let args = self
+            .sources(index)
+            .into_iter()
+            .map(|source| match path.contains(&source) {
+                true => previous(source, format),
+                false => self.render_node(source, input_names, format, path),
+            })
+            .collect::<Vec<String>>();
+
+        path.remove(&index);
+
+        match &node.value {
+            Ops::Value(value) | Ops::Const(_, value) => literal(value, format),
+            Ops::MutableConst(_, _, value, _, _) => {
+                let weight = literal(value, format);
+                let input = args
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| literal(&T::default(), format));
+                match format {
+                    ExpressionFormat::Latex => format!("{} \\cdot {}", weight, input),
+                    _ => format!("({} * {})", weight, input),
+                }
+            }
+            Ops::Var(_, _) => args
+                .first()
+                .cloned()
+                .unwrap_or_else(|| literal(&T::default(), format)),
+            Ops::Fn(name, _, _) => render_fn(name, &args, format),
+            Ops::Indexed(name, index, _) => {
+                render_fn(&format!("{}_{}", name, index), &args, format)
+            }
+        }
+    }
+}
+
+fn input_name<T>(node: &Node<T>, input_names: &[&str]) -> String
+where
+    T: Clone + PartialEq,
+{
+    match input_names.get(node.index) {
+        Some(name) => name.to_string(),
+        None => match &node.value {
+            Ops::Var(name, _) => name.clone(),
+            _ => format!("x{}", node.index),
+        },
+    }
+}
+
+fn previous(index: usize, format: ExpressionFormat) -> String {
+    match format {
+        ExpressionFormat::Infix => format!("n{}[t-1]", index),
+        ExpressionFormat::Latex => format!("n_{{{}}}^{{(t-1)}}", index),
+        ExpressionFormat::Rust => format!("prev[{}]", index),
+    }
+}
+
+fn literal<T: std::fmt::Debug>(value: &T, format: ExpressionFormat) -> String {
+    let text = match format {
+        ExpressionFormat::Rust => format!("{:?}_{}", value, std::any::type_name::<T>()),
+        _ => format!("{:?}", value),
+    };
+    match text.starts_with('-') {
+        true => format!("({})", text),
+        false => text,
+    }
+}
+
+fn render_fn(name: &str, args: &[String], format: ExpressionFormat) -> String {
+    let joined = |separator: &str| match args.len() {
+        1 => args[0].clone(),
+        _ => format!("({})", args.join(separator)),
+    };
+
+    if ACTIVATIONS.contains(&name) || name == "linear" {
+        return match (name, format) {
+            ("linear", _) | (_, ExpressionFormat::Rust) => {
+                let sum = joined(" + ");
+                match name {
+                    "linear" => sum,
+                    _ => render_activation(name, &sum, format),
+                }
+            }
+            _ => render_activation(name, &args.join(" + "), format),
+        };
+    }
+
+    match (name, args) {
+        ("+" | "-" | "*" | "/" | "pow" | ">" | "<", [left, right]) => {
+            render_binary(name, left, right, format)
+        }
+        ("sum", _) => joined(" + "),
+        ("prod", _) => match format {
+            ExpressionFormat::Latex => joined(" \\cdot "),
+            _ => joined(" * "),
+        },
+        ("neg", [arg]) => format!("(-{})", arg),
+        ("max" | "min", [first, rest @ ..]) => match format {
+            ExpressionFormat::Rust => rest.iter().fold(first.clone(), |acc, arg| {
+                format!("{}.{}({})", acc, name, arg)
+            }),
+            ExpressionFormat::Latex => {
+                format!("\\{}\\left({}\\right)", name, args.join(", "))
+            }
+            ExpressionFormat::Infix => format!("{}({})", name, args.join(", ")),
+        },
+        (_, [arg]) => render_unary(name, arg, format),
+        _ => match format {
+            ExpressionFormat::Latex => {
+                format!(
+                    "\\operatorname{{{}}}\\left({}\\right)",
+                    name,
+                    args.join(", ")
+                )
+            }
+            _ => format!("{}({})", name, args.join(", ")),
+        },
+    }
+}
+
+fn render_binary(name: &str, left: &str, right: &str, format: ExpressionFormat) -> String {
+    match (name, format) {
+        (">", ExpressionFormat::Rust) => format!("{}.max({})", left, right),
+        ("<", ExpressionFormat::Rust) => format!("{}.min({})", left, right),
+        (">", ExpressionFormat::Latex) => format!("\\max\\left({}, {}\\right)", left, right),
+        ("<", ExpressionFormat::Latex) => format!("\\min\\left({}, {}\\right)", left, right),
+        (">", ExpressionFormat::Infix) => format!("max({}, {})", left, right),
+        ("<", ExpressionFormat::Infix) => format!("min({}, {})", left, right),
+        ("pow", ExpressionFormat::Rust) => format!("{}.powf({})", left, right),
+        ("pow", ExpressionFormat::Latex) => format!("{{{}}}^{{{}}}", left, right),
+        ("pow", ExpressionFormat::Infix) => format!("pow({}, {})", left, right),
+        ("*", ExpressionFormat::Latex) => format!("{} \\cdot {}", left, right),
+        ("/", ExpressionFormat::Latex) => format!("\\frac{{{}}}{{{}}}", left, right),
+        _ => format!("({} {} {})", left, name, right),
+    }
+}
+
+fn render_unary(name: &str, arg: &str, format: ExpressionFormat) -> String {
+    match format {
+        ExpressionFormat::Infix => format!("{}({})", name, arg),
+        ExpressionFormat::Rust => match name {
+            "log" => format!("{}.ln()", arg),
+            _ => format!("{}.{}()", arg, name),
+        },
+        ExpressionFormat::Latex => match name {
+            "sqrt" => format!("\\sqrt{{{}}}", arg),
+            "abs" => format!("\\left|{}\\right|", arg),
+            "exp" => format!("e^{{{}}}", arg),
+            "log" => format!("\\ln\\left({}\\right)", arg),
+            "sin" | "cos" | "tan" => format!("\\{}\\left({}\\right)", name, arg),
+            "ceil" => format!("\\lceil {} \\rceil", arg),
+            "floor" => format!("\\lfloor {} \\rfloor", arg),
+            _ => format!("\\operatorname{{{}}}\\left({}\\right)", name, arg),
+        },
+    }
+}
+
+fn render_activation(name: &str, sum: &str, format: ExpressionFormat) -> String {
+    match format {
+        ExpressionFormat::Infix => format!("{}({})", name, sum),
+        ExpressionFormat::Latex => match name {
+            "sigmoid" => format!("\\sigma\\left({}\\right)", sum),
+            "tanh" => format!("\\tanh\\left({}\\right)", sum),
+            _ => format!("\\operatorname{{{}}}\\left({}\\right)", name, sum),
+        },
+        ExpressionFormat::Rust => match name {
+            "sigmoid" => format!("(1.0 / (1.0 + (-{}).exp()))", sum),
+            "relu" => format!("{}.max(0.0)", sum),
+            "tanh" => format!("{}.tanh()", sum),
+            "mish" => format!("({}.tanh() * {}.exp().ln_1p().exp())", sum, sum),
+            "l_relu" => format!("{}.max(0.01 * {})", sum, sum),
+            _ => format!("{}.exp().ln_1p()", sum),
+        },
+    }
+}
@@ -0,0 +1,479 @@
+use std::collections::HashSet;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::architects::node_collections::graph::Graph;
+use crate::architects::node_collections::node::Node;
+use crate::architects::schema::direction::Direction;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+use crate::operations::op_registry::OpRegistry;
+
+impl<T> Graph<T>
+where
+    T: Clone + PartialEq + Default + std::fmt::Display + FromStr,
+{
+    pub fn to_json(&self) -> String {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| {
+                format!(
+                    "    {{\"index\": {}, \"innovation\": {}, \"type\": \"{:?}\", \"direction\": \"{:?}\", \"enabled\": {}, \"arity\": {}, \"op\": {}}}",
+                    node.index,
+                    node.innovation,
+                    node.node_type,
+                    node.direction,
+                    node.enabled,
+                    match node.arity {
+                        Some(arity) => arity.to_string(),
+                        None => String::from("null"),
+                    },
+                    op_json(&node.value)
+                )
+            })
+            .collect::<Vec<String>>();
+
+        let mut edges = self
+            .nodes
+            .iter()
+            .flat_map(|node| {
+                node.outgoing
+                    .iter()
+                    .map(move |target| (node.index, *target))
+            })
+            .collect::<Vec<(usize, usize)>>();
+        edges.sort();
+
+        format!(
+            "{{\n  \"nodes\": [\n{}\n  ],\n  \"edges\": [{}]\n}}\n",
+            nodes.join(",\n"),
+            edges
+                .iter()
+                .map(|(source, target)| format!("[{}, {}]", source, target))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    pub fn from_json(text: &str, registry: &OpRegistry<T>) -> std::io::Result<Graph<T>> {
+        let json = JsonParser::new(text).parse()?;
+
+        let mut nodes = Vec::new();
+        for (position, node) in json.field("nodes")?.items()?.iter().enumerate() {
+            let index = node.field("index")?.number::<usize>()?;
+            if index != position {
+                return Err(invalid(format!(
+                    "node {} is stored at position {}",
+                    index, position
+                )));
+            }
+
+            let value = parse_op(node.field("op")?, registry)?;
+            let arity = match node.field("arity")? {
+                Json::Null => None,
+                arity => Some(arity.number::<u8>()?),
+            };
+
+            nodes.push(Node {
+                id: uuid::Uuid::new_v4(),
+                index,
+                innovation: node.field("innovation")?.number::<usize>()?,
+                value,
+                arity,
+                enabled: node.field("enabled")?.boolean()?,
+                node_type: parse_node_type(node.field("type")?.string()?)?,
+                direction: parse_direction(node.field("direction")?.string()?)?,
+                incoming: HashSet::new(),
+                outgoing: HashSet::new(),
+            });
+        }
+
+        for edge in json.field("edges")?.items()? {
+            let pair = edge.items()?;
+            if pair.len() != 2 {
+                return Err(invalid(String::from(
+                    "edges must be [source, target] pairs",
+                )));
+            }
+
+            let source = pair[0].number::<usize>()?;
+            let target = pair[1].number::<usize>()?;
+            if source >= nodes.len() || target >= nodes.len() {
+                return Err(invalid(format!(
+                    "edge [{}, {}] is out of range",
+                    source, target
+                )));
+            }
+
+            nodes[source].outgoing.insert(target);
+            nodes[target].incoming.insert(source);
+        }
+
+        Ok(Graph { nodes })
+    }
+
+    pub fn save_json(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+
+    pub fn load_json(
+        path: impl AsRef<Path>,
+        registry: &OpRegistry<T>,
+    ) -> std::io::Result<Graph<T>> {
+        Graph::from_json(&std::fs::read_to_string(path)?, registry)
+    }
+}
+
+fn op_json<T>(value: &Ops<T>) -> String
+where
+    T: Clone + std::fmt::Display,
+{
+    match value {
+        Ops::Fn(name, _, _) => format!("{{\"kind\": \"Fn\", \"name\": {}}}", json_string(name)),
+        Ops::Indexed(name, index, _) => format!(
+            "{{\"kind\": \"Indexed\", \"name\": {}, \"index\": {}}}",
+            json_string(name),
+            index
+        ),
+        Ops::Value(value) => format!("{{\"kind\": \"Value\", \"value\": {}}}", json_value(value)),
+        Ops::Var(name, index) => format!(
+            "{{\"kind\": \"Var\", \"name\": {}, \"index\": {}}}",
+            json_string(name),
+            index
+        ),
+        Ops::Const(name, value) => format!(
+            "{{\"kind\": \"Const\", \"name\": {}, \"value\": {}}}",
+            json_string(name),
+            json_value(value)
+        ),
+        Ops::MutableConst(name, _, value, _, _) => format!(
+            "{{\"kind\": \"MutableConst\", \"name\": {}, \"value\": {}}}",
+            json_string(name),
+            json_value(value)
+        ),
+    }
+}
+
+fn parse_op<T>(json: &Json, registry: &OpRegistry<T>) -> std::io::Result<Ops<T>>
+where
+    T: Clone + FromStr,
+{
+    let kind = json.field("kind")?.string()?;
+    if kind == "Value" {
+        return Ok(Ops::Value(json.field("value")?.value::<T>()?));
+    }
+
+    let name = json.field("name")?.string()?;
+    if kind == "Var" {
+        return Ok(Ops::Var(
+            name.to_string(),
+            json.field("index")?.number::<usize>()?,
+        ));
+    }
+
+    if kind == "Indexed" {
+        let index = json.field("index")?.number::<usize>()?;
+        return registry
+            .create_indexed(name, index)
+            .ok_or_else(|| invalid(format!("op '{}' is not registered as Indexed", name)));
+    }
+
+    let registered = registry
+        .create(name)
+        .ok_or_else(|| invalid(format!("op '{}' is not registered", name)))?;
+
+    match (kind, registered) {
+        ("Fn", registered @ Ops::Fn(..)) => Ok(registered),
+        ("Const", Ops::Const(name, _)) => Ok(Ops::Const(name, json.field("value")?.value::<T>()?)),
+        ("MutableConst", Ops::MutableConst(name, arity, _, supplier, operation)) => {
+            Ok(Ops::MutableConst(
+                name,
+                arity,
+                json.field("value")?.value::<T>()?,
+                supplier,
+                operation,
+            ))
+        }
+        (kind, _) => Err(invalid(format!(
+            "op '{}' is not registered as {}",
+            name, kind
+        ))),
+    }
+}
+
+fn parse_node_type(name: &str) -> std::io::Result<NodeType> {
+    match name {
+        "Input" => Ok(NodeType::Input),
+        "Output" => Ok(NodeType::Output),
+        "Gate" => Ok(NodeType::Gate),
+        "Aggregate" => Ok(NodeType::Aggregate),
+        "Weight" => Ok(NodeType::Weight),
+        "Link" => Ok(NodeType::Link),
+        _ => Err(invalid(format!("unknown node type '{}'", name))),
+    }
+}
+
+fn parse_direction(name: &str) -> std::io::Result<Direction> {
+    match name {
+        "Forward" => Ok(Direction::Forward),
+        "Backward" => Ok(Direction::Backward),
+        _ => Err(invalid(format!("unknown direction '{}'", name))),
+    }
+}
+
+// JSON has no NaN or infinity, so values that do not print as JSON numbers are
+// written as strings and parsed back through FromStr.
+fn json_value<T: std::fmt::Display>(value: &T) -> String {
+    let text = value.to_string();
+    match is_json_number(&text) {
+        true => text,
+        false => json_string(&text),
+    }
+}
+
+fn is_json_number(text: &str) -> bool {
+    fn digits(text: &str) -> (usize, &str) {
+        let count = text.chars().take_while(|c| c.is_ascii_digit()).count();
+        (count, &text[count..])
+    }
+
+    let integer = text.strip_prefix('-').unwrap_or(text);
+    let (count, rest) = digits(integer);
+    if count == 0 || (count > 1 && integer.starts_with('0')) {
+        return false;
+    }
+
+    let rest = match rest.strip_prefix('.') {
+        Some(fraction) => match digits(fraction) {
+            (0, _) => return false,
+            (_, rest) => rest,
+        },
+        None => rest,
+    };
+
+    let rest = match rest.strip_prefix(['e', 'E']) {
+        Some(exponent) => match digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)) {
+            (0, _) => return false,
+            (_, rest) => rest,
+        },
+        None => rest,
+    };
+
+    rest.is_empty()
+}
+
+fn json_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn invalid(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn field(&self, name: &str) -> std::io::Result<&Json> {
+        match self {
+            Json::Object(fields) => fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value)
+                .ok_or_else(|| invalid(format!("missing field '{}'", name))),
+            _ => Err(invalid(format!("expected an object with field '{}'", name))),
+        }
+    }
+
+    fn items(&self) -> std::io::Result<&[Json]> {
+        match self {
+            Json::Array(items) => Ok(items),
+            _ => Err(invalid(String::from("expected an array"))),
+        }
+    }
+
+    fn string(&self) -> std::io::Result<&str> {
+        match self {
+            Json::String(value) => Ok(value),
+            _ => Err(invalid(String::from("expected a string"))),
+        }
+    }
+
+    fn boolean(&self) -> std::io::Result<bool> {
+        match self {
+            Json::Bool(value) => Ok(*value),
+            _ => Err(invalid(String::from("expected a boolean"))),
+        }
+    }
+
+    fn number<N: FromStr>(&self) -> std::io::Result<N> {
+        match self {
+            Json::Number(value) => value
+                .parse::<N>()
+                .map_err(|_| invalid(format!("invalid number '{}'", value))),
+            _ => Err(invalid(String::from("expected a number"))),
+        }
+    }
+
+    fn value<N: FromStr>(&self) -> std::io::Result<N> {
+        match self {
+            Json::String(value) => value
+                .parse::<N>()
+                .map_err(|_| invalid(format!("invalid value '{}'", value))),
+            _ => self.number(),
+        }
+    }
+}
+
+struct JsonParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(text: &'a str) -> Self {
+        JsonParser {
+            chars: text.chars().peekable(),
+        }
+    }
+
+    fn parse(mut self) -> std::io::Result<Json> {
+        let value = self.value()?;
+        self.skip_whitespace();
+
+        match self.chars.next() {
+            None => Ok(value),
+            Some(c) => Err(invalid(format!("unexpected trailing character '{}'", c))),
+        }
+    }
+
+    fn value(&mut self) -> std::io::Result<Json> {
+        self.skip_whitespace();
+
+        match self.chars.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => Ok(Json::String(self.string()?)),
+            Some('t') => self.literal("true", Json::Bool(true)),
+            Some('f') => self.literal("false", Json::Bool(false)),
+            Some('n') => self.literal("null", Json::Null),
+            Some(_) => self.number(),
+            None => Err(invalid(String::from("unexpected end of input"))),
+        }
+    }
+
+    fn object(&mut self) -> std::io::Result<Json> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.chars.next();
+            return Ok(Json::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+
+            match self.chars.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Json::Object(fields)),
+                _ => return Err(invalid(String::from("expected ',' or '}'"))),
+            }
+        }
+    }
+
+    fn array(&mut self) -> std::io::Result<Json> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.chars.next();
+            return Ok(Json::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+
+            match self.chars.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Json::Array(items)),
+                _ => return Err(invalid(String::from("expected ',' or ']'"))),
+            }
+        }
+    }
+
+    fn string(&mut self) -> std::io::Result<String> {
+        self.expect('"')?;
+        let mut result = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(result),
+                Some('\\') => match self.chars.next() {
+                    Some('n') => result.push('\n'),
+                    Some('t') => result.push('\t'),
+                    Some(c) => result.push(c),
+                    None => return Err(invalid(String::from("unterminated escape"))),
+                },
+                Some(c) => result.push(c),
+                None => return Err(invalid(String::from("unterminated string"))),
+            }
+        }
+    }
+
+    fn number(&mut self) -> std::io::Result<Json> {
+        let mut result = String::new();
+        while let Some(c) = self.chars.peek() {
+            if c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E') {
+                result.push(*c);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+
+        match (result.is_empty(), is_json_number(&result)) {
+            (true, _) => Err(invalid(String::from("expected a value"))),
+            (false, false) => Err(invalid(format!("invalid number '{}'", result))),
+            (false, true) => Ok(Json::Number(result)),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> std::io::Result<Json> {
+        for expected in word.chars() {
+            if self.chars.next() != Some(expected) {
+                return Err(invalid(format!("expected '{}'", word)));
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn expect(&mut self, expected: char) -> std::io::Result<()> {
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(invalid(format!("expected '{}'", expected))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use num_traits::Float;
+use radiate_rust::engines::genome::genotype::Genotype;
+
+use crate::architects::node_collections::node::Node;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+
+pub type Innovations = Arc<RwLock<InnovationRegistry>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InnovationKey {
+    Split(Vec<usize>, Vec<usize>, NodeType, usize),
+    Edge(usize, usize),
+}
+
+pub struct InnovationRegistry {
+    innovations: HashMap<InnovationKey, usize>,
+    next: usize,
+}
+
+impl InnovationRegistry {
+    pub fn new() -> Self {
+        InnovationRegistry {
+            innovations: HashMap::new(),
+            next: 0,
+        }
+    }
+
+    pub fn shared() -> Innovations {
+        Arc::new(RwLock::new(InnovationRegistry::new()))
+    }
+
+    pub fn get(&self, key: &InnovationKey) -> Option<usize> {
+        self.innovations.get(key).cloned()
+    }
+
+    pub fn innovation(&mut self, key: InnovationKey) -> usize {
+        if let Some(innovation) = self.innovations.get(&key) {
+            return *innovation;
+        }
+
+        let innovation = self.fresh();
+        self.innovations.insert(key, innovation);
+        innovation
+    }
+
+    pub fn fresh(&mut self) -> usize {
+        let innovation = self.next;
+        self.next += 1;
+        innovation
+    }
+
+    pub fn reserve(&mut self, next: usize) {
+        self.next = self.next.max(next);
+    }
+
+    pub fn edge_innovation<T>(
+        &self,
+        nodes: &[Node<T>],
+        source: usize,
+        target: usize,
+    ) -> Option<usize>
+    where
+        T: Clone + PartialEq,
+    {
+        self.get(&edge_key(nodes, source, target))
+    }
+
+    pub fn assign_nodes<T>(&mut self, nodes: &mut [Node<T>], first_new: usize)
+    where
+        T: Clone + PartialEq,
+    {
+        self.reserve_for(&nodes[..first_new]);
+
+        let mut seen = nodes[..first_new]
+            .iter()
+            .map(|node| node.innovation)
+            .collect::<HashSet<usize>>();
+
+        for index in first_new..nodes.len() {
+            let neighbours = |edges: &HashSet<usize>| {
+                let mut innovations = edges
+                    .iter()
+                    .filter(|other| **other < first_new)
+                    .map(|other| nodes[*other].innovation)
+                    .collect::<Vec<usize>>();
+                innovations.sort();
+                innovations
+            };
+
+            let key = InnovationKey::Split(
+                neighbours(&nodes[index].incoming),
+                neighbours(&nodes[index].outgoing),
+                nodes[index].node_type,
+                index - first_new,
+            );
+
+            let mut innovation = self.innovation(key);
+            if seen.contains(&innovation) {
+                innovation = self.fresh();
+            }
+
+            seen.insert(innovation);
+            nodes[index].innovation = innovation;
+        }
+    }
+
+    pub fn assign_edges<T>(&mut self, nodes: &[Node<T>])
+    where
+        T: Clone + PartialEq,
+    {
+        self.reserve_for(nodes);
+
+        for node in nodes.iter() {
+            for target in node.outgoing.iter() {
+                self.innovation(edge_key(nodes, node.index, *target));
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.innovations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.innovations.is_empty()
+    }
+
+    fn reserve_for<T>(&mut self, nodes: &[Node<T>])
+    where
+        T: Clone + PartialEq,
+    {
+        if let Some(innovation) = nodes.iter().map(|node| node.innovation).max() {
+            self.reserve(innovation + 1);
+        }
+    }
+}
+
+impl Default for InnovationRegistry {
+    fn default() -> Self {
+        InnovationRegistry::new()
+    }
+}
+
+pub fn edge_key<T>(nodes: &[Node<T>], source: usize, target: usize) -> InnovationKey
+where
+    T: Clone + PartialEq,
+{
+    InnovationKey::Edge(nodes[source].innovation, nodes[target].innovation)
+}
+
+pub fn node_genes<T>(nodes: &[Node<T>]) -> HashMap<usize, usize>
+where
+    T: Clone + PartialEq,
+{
+    nodes
+        .iter()
+        .map(|node| (node.innovation, node.index))
+        .collect::<HashMap<usize, usize>>()
+}
+
+pub fn edge_genes<T>(nodes: &[Node<T>]) -> HashSet<(usize, usize)>
+where
+    T: Clone + PartialEq,
+{
+    nodes
+        .iter()
+        .flat_map(|node| {
+            node.outgoing
+                .iter()
+                .map(move |target| (node.innovation, nodes[*target].innovation))
+        })
+        .collect::<HashSet<(usize, usize)>>()
+}
+
+pub fn compatibility_distance<T>(
+    one: &[Node<T>],
+    two: &[Node<T>],
+    innovations: &InnovationRegistry,
+    excess_coefficient: f32,
+    disjoint_coefficient: f32,
+    weight_coefficient: f32,
+) -> f32
+where
+    T: Clone + PartialEq + Float,
+{
+    let nodes_one = node_genes(one);
+    let nodes_two = node_genes(two);
+    let edges_one = edge_genes(one);
+    let edges_two = edge_genes(two);
+
+    let edge_innovation =
+        |(source, target): &(usize, usize)| innovations.get(&InnovationKey::Edge(*source, *target));
+    let known = |nodes: &HashMap<usize, usize>, edges: &HashSet<(usize, usize)>| {
+        nodes
+            .keys()
+            .cloned()
+            .chain(edges.iter().filter_map(edge_innovation))
+            .max()
+            .unwrap_or(0)
+    };
+
+    let excess_limit = std::cmp::min(known(&nodes_one, &edges_one), known(&nodes_two, &edges_two));
+
+    let mut excess = 0;
+    let mut disjoint = 0;
+    let unmatched = nodes_one
+        .keys()
+        .filter(|gene| !nodes_two.contains_key(gene))
+        .chain(
+            nodes_two
+                .keys()
+                .filter(|gene| !nodes_one.contains_key(gene)),
+        )
+        .map(|gene| Some(*gene))
+        .chain(
+            edges_one
+                .symmetric_difference(&edges_two)
+                .map(edge_innovation),
+        );
+
+    for gene in unmatched {
+        match gene {
+            Some(gene) if gene > excess_limit => excess += 1,
+            _ => disjoint += 1,
+        }
+    }
+
+    let mut weight_difference = 0_f32;
+    let mut matching_weights = 0;
+    for (innovation, index_one) in nodes_one.iter() {
+        if let Some(index_two) = nodes_two.get(innovation) {
+            if let (
+                Ops::MutableConst(_, _, value_one, _, _),
+                Ops::MutableConst(_, _, value_two, _, _),
+            ) = (&one[*index_one].value, &two[*index_two].value)
+            {
+                weight_difference += (*value_one - *value_two).abs().to_f32().unwrap_or(0_f32);
+                matching_weights += 1;
+            }
+        }
+    }
+
+    let size = std::cmp::max(
+        nodes_one.len() + edges_one.len(),
+        nodes_two.len() + edges_two.len(),
+    );
+    let normalizer = if size < 20 { 1_f32 } else { size as f32 };
+    let average_weight = match matching_weights {
+        0 => 0_f32,
+        count => weight_difference / count as f32,
+    };
+
+    excess_coefficient * excess as f32 / normalizer
+        + disjoint_coefficient * disjoint as f32 / normalizer
+        + weight_coefficient * average_weight
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Compatibility {
+    pub excess: f32,
+    pub disjoint: f32,
+    pub weight: f32,
+}
+
+impl Compatibility {
+    pub fn new(excess: f32, disjoint: f32, weight: f32) -> Self {
+        Compatibility {
+            excess,
+            disjoint,
+            weight,
+        }
+    }
+}
+
+impl Default for Compatibility {
+    fn default() -> Self {
+        Compatibility::new(1.0, 1.0, 0.4)
+    }
+}
+
+pub fn graph_distance<T>(
+    one: &Genotype<Node<T>, Ops<T>>,
+    two: &Genotype<Node<T>, Ops<T>>,
+    innovations: &InnovationRegistry,
+    compatibility: &Compatibility,
+) -> f32
+where
+    T: Clone + PartialEq + Default + Float,
+{
+    one.iter()
+        .zip(two.iter())
+        .map(|(chrom_one, chrom_two)| {
+            compatibility_distance(
+                &chrom_one.genes,
+                &chrom_two.genes,
+                innovations,
+                compatibility.excess,
+                compatibility.disjoint,
+                compatibility.weight,
+            )
+        })
+        .sum::<f32>()
+}
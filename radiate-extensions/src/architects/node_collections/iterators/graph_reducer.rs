@@ -12,7 +12,9 @@ where
     pub graph: &'a Graph<T>,
     pub tracers: Vec<Tracer<T>>,
     pub order: Vec<usize>,
+    pub sources: Vec<Vec<usize>>,
     pub outputs: Vec<T>,
+    pub settling: usize,
 }
 
 impl<'a, T> GraphReducer<'a, T>
@@ -29,19 +31,65 @@ where
             graph,
             tracers: graph
                 .iter()
-                .map(|node| Tracer::new(GraphReducer::input_size(node)))
+                .map(|node| Tracer::new(GraphReducer::input_size(graph, node)))
                 .collect::<Vec<Tracer<T>>>(),
             order: Vec::with_capacity(graph.len()),
+            sources: graph
+                .iter()
+                .map(|node| graph.sources(node.index))
+                .collect::<Vec<Vec<usize>>>(),
             outputs: vec![T::default(); output_size],
+            settling: 1,
+        }
+    }
+
+    pub fn with_settling(mut self, settling: usize) -> Self {
+        self.settling = settling.max(1);
+        self
+    }
+
+    pub fn reset(&mut self) {
+        for tracer in self.tracers.iter_mut() {
+            tracer.reset();
+        }
+    }
+
+    pub fn step(&mut self, inputs: &[T]) -> Vec<T> {
+        for _ in 1..self.settling {
+            self.reduce(inputs);
+        }
+
+        self.reduce(inputs)
+    }
+
+    pub fn run_sequence(&mut self, sequence: &[Vec<T>]) -> Vec<Vec<T>> {
+        self.reset();
+        sequence.iter().map(|inputs| self.step(inputs)).collect()
+    }
+
+    pub fn snapshot(&self) -> Vec<(Option<T>, Option<T>)> {
+        self.tracers
+            .iter()
+            .map(|tracer| (tracer.result.clone(), tracer.previous_result.clone()))
+            .collect()
+    }
+
+    pub fn restore(&mut self, state: &[(Option<T>, Option<T>)]) {
+        for (tracer, (result, previous)) in self.tracers.iter_mut().zip(state.iter()) {
+            tracer.reset();
+            tracer.result = result.clone();
+            tracer.previous_result = previous.clone();
         }
     }
 
     #[inline]
     pub fn reduce(&mut self, inputs: &[T]) -> Vec<T> {
         if self.order.is_empty() {
+            let active = self.graph.active_nodes();
             self.order = self
                 .graph
                 .topological_iter()
+                .filter(|node| active.contains(&node.index))
                 .map(|node| node.index)
                 .collect();
         }
@@ -52,7 +100,7 @@ where
                 if node.node_type == NodeType::Input {
                     self.tracers[node.index].add_input(inputs[node.index].clone());
                 } else {
-                    for incoming in &node.incoming {
+                    for incoming in &self.sources[node.index] {
                         let arg = self.tracers[*incoming]
                             .result
                             .clone()
@@ -73,11 +121,11 @@ where
         self.outputs.clone()
     }
 
-    fn input_size(node: &Node<T>) -> usize {
+    fn input_size(graph: &Graph<T>, node: &Node<T>) -> usize {
         match node.node_type {
             NodeType::Input | NodeType::Link => 1,
             NodeType::Gate => node.value.arity() as usize,
-            _ => node.incoming.len(),
+            _ => graph.sources(node.index).len(),
         }
     }
 }
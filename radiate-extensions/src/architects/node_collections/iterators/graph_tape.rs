@@ -0,0 +1,174 @@
+use crate::architects::node_collections::graph::Graph;
+use crate::architects::node_collections::node_collection::NodeCollection;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+use crate::problems::sample_set::SampleSet;
+
+pub struct TapeInstruction<T>
+where
+    T: Clone,
+{
+    pub slot: usize,
+    pub value: Ops<T>,
+    pub input: Option<usize>,
+    pub sources: (usize, usize),
+}
+
+pub struct GraphTape<T>
+where
+    T: Clone,
+{
+    pub instructions: Vec<TapeInstruction<T>>,
+    pub sources: Vec<usize>,
+    pub outputs: Vec<usize>,
+    pub values: Vec<T>,
+    pub settling: usize,
+    args: Vec<T>,
+}
+
+impl<T> GraphTape<T>
+where
+    T: Clone + PartialEq + Default,
+{
+    pub fn compile(graph: &Graph<T>) -> Self {
+        let active = graph.active_nodes();
+        let mut instructions = Vec::with_capacity(active.len());
+        let mut sources = Vec::new();
+        let mut outputs = Vec::new();
+        let mut max_args = 1;
+
+        for node in graph
+            .topological_iter()
+            .filter(|node| active.contains(&node.index))
+        {
+            let start = sources.len();
+            let input = match node.node_type {
+                NodeType::Input => Some(node.index),
+                _ => {
+                    sources.extend(graph.sources(node.index));
+                    None
+                }
+            };
+
+            max_args = max_args.max(sources.len() - start);
+
+            if node.node_type == NodeType::Output {
+                outputs.push(node.index);
+            }
+
+            instructions.push(TapeInstruction {
+                slot: node.index,
+                value: match node.enabled {
+                    true => node.value.clone(),
+                    false => Ops::Value(T::default()),
+                },
+                input,
+                sources: (start, sources.len()),
+            });
+        }
+
+        GraphTape {
+            instructions,
+            sources,
+            outputs,
+            values: vec![T::default(); graph.len()],
+            settling: 1,
+            args: vec![T::default(); max_args],
+        }
+    }
+
+    pub fn with_settling(mut self, settling: usize) -> Self {
+        self.settling = settling.max(1);
+        self
+    }
+
+    pub fn reset(&mut self) {
+        for value in self.values.iter_mut() {
+            *value = T::default();
+        }
+    }
+
+    pub fn step(&mut self, inputs: &[T]) -> Vec<T> {
+        let mut outputs = vec![T::default(); self.outputs.len()];
+        for _ in 0..self.settling {
+            self.eval_into(inputs, &mut outputs);
+        }
+
+        outputs
+    }
+
+    pub fn run_sequence(&mut self, sequence: &[Vec<T>]) -> Vec<Vec<T>> {
+        self.reset();
+        sequence.iter().map(|inputs| self.step(inputs)).collect()
+    }
+
+    pub fn snapshot(&self) -> Vec<T> {
+        self.values.clone()
+    }
+
+    pub fn restore(&mut self, state: &[T]) {
+        self.values.clone_from_slice(state);
+    }
+
+    #[inline]
+    pub fn eval_into(&mut self, inputs: &[T], outputs: &mut [T]) {
+        for instruction in self.instructions.iter() {
+            let count = match instruction.input {
+                Some(index) => {
+                    self.args[0] = inputs[index].clone();
+                    1
+                }
+                None => {
+                    let (start, end) = instruction.sources;
+                    for (arg, source) in self.args.iter_mut().zip(&self.sources[start..end]) {
+                        *arg = self.values[*source].clone();
+                    }
+                    end - start
+                }
+            };
+            let args = &self.args[..count];
+
+            self.values[instruction.slot] = match &instruction.value {
+                Ops::Value(value) => value.clone(),
+                Ops::Const(_, value) => value.clone(),
+                Ops::Fn(_, _, fn_ptr) => fn_ptr(args),
+                Ops::Indexed(_, index, fn_ptr) => fn_ptr(args, *index),
+                Ops::MutableConst(_, _, value, _, fn_ptr) => fn_ptr(args, value),
+                Ops::Var(_, _) => args[0].clone(),
+            };
+        }
+
+        for (output, slot) in outputs.iter_mut().zip(self.outputs.iter()) {
+            *output = self.values[*slot].clone();
+        }
+    }
+
+    pub fn eval(&mut self, inputs: &[T]) -> Vec<T> {
+        let mut outputs = vec![T::default(); self.outputs.len()];
+        self.eval_into(inputs, &mut outputs);
+        outputs
+    }
+
+    pub fn eval_batch(&mut self, samples: &SampleSet<T>) -> Vec<Vec<T>> {
+        let mut results = vec![vec![T::default(); self.outputs.len()]; samples.get_samples().len()];
+
+        for (sample, outputs) in samples.get_samples().iter().zip(results.iter_mut()) {
+            self.eval_into(&sample.1, outputs);
+        }
+
+        results
+    }
+
+    pub fn output_size(&self) -> usize {
+        self.outputs.len()
+    }
+}
+
+impl<T> Graph<T>
+where
+    T: Clone + PartialEq + Default,
+{
+    pub fn compile(&self) -> GraphTape<T> {
+        GraphTape::compile(self)
+    }
+}
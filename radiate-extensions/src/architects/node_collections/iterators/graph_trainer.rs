@@ -0,0 +1,221 @@
+use radiate_rust::engines::genome::genotype::Genotype;
+
+use crate::architects::node_collections::graph::Graph;
+use crate::architects::node_collections::iterators::graph_tape::GraphTape;
+use crate::architects::node_collections::node::Node;
+use crate::architects::node_collections::node_collection::NodeCollection;
+use crate::operations::op::Ops;
+use crate::operations::op_derivative::{const_derivative, derivative};
+use crate::problems::optimizer::{Optimizer, OptimizerState};
+use crate::problems::regression::Regression;
+
+pub struct GraphTrainer {
+    pub optimizer: Optimizer,
+    pub steps: usize,
+    pub truncation: Option<usize>,
+    pub settling: usize,
+    pub clip: Option<f32>,
+}
+
+impl GraphTrainer {
+    pub fn new(optimizer: Optimizer) -> Self {
+        GraphTrainer {
+            optimizer,
+            steps: 10,
+            truncation: None,
+            settling: 1,
+            clip: None,
+        }
+    }
+
+    pub fn steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    pub fn truncation(mut self, truncation: usize) -> Self {
+        self.truncation = Some(truncation.max(1));
+        self
+    }
+
+    pub fn settling(mut self, settling: usize) -> Self {
+        self.settling = settling.max(1);
+        self
+    }
+
+    pub fn clip(mut self, clip: f32) -> Self {
+        self.clip = Some(clip.abs());
+        self
+    }
+
+    pub fn gradients(&self, graph: &Graph<f32>, regression: &Regression<f32>) -> (f32, Vec<f32>) {
+        let mut tape = GraphTape::compile(graph).with_settling(self.settling);
+        let (loss, gradients) = self.backward(&mut tape, regression);
+
+        let mut result = vec![0_f32; graph.nodes.len()];
+        for (instruction, gradient) in tape.instructions.iter().zip(gradients) {
+            result[instruction.slot] = gradient;
+        }
+
+        (loss, result)
+    }
+
+    pub fn train(&self, graph: &mut Graph<f32>, regression: &Regression<f32>) -> f32 {
+        let mut tape = GraphTape::compile(graph).with_settling(self.settling);
+        let params = tape
+            .instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| matches!(instruction.value, Ops::MutableConst(..)))
+            .map(|(position, _)| position)
+            .collect::<Vec<usize>>();
+
+        if !params.is_empty() {
+            let mut state = OptimizerState::default();
+            let mut values = params
+                .iter()
+                .map(|position| constant(&tape.instructions[*position].value))
+                .collect::<Vec<f32>>();
+
+            for _ in 0..self.steps {
+                let (_, gradients) = self.backward(&mut tape, regression);
+                let gradients = params
+                    .iter()
+                    .map(|position| match self.clip {
+                        Some(clip) => gradients[*position].clamp(-clip, clip),
+                        None => gradients[*position],
+                    })
+                    .collect::<Vec<f32>>();
+
+                self.optimizer.update(&mut values, &gradients, &mut state);
+
+                for (position, value) in params.iter().zip(values.iter()) {
+                    set_constant(&mut tape.instructions[*position].value, *value);
+                }
+            }
+
+            for position in params.iter() {
+                let instruction = &tape.instructions[*position];
+                graph.nodes[instruction.slot].value = instruction.value.clone();
+            }
+        }
+
+        regression.sequence_error(|sequence| tape.run_sequence(sequence))
+    }
+
+    pub fn train_genotype(
+        &self,
+        genotype: &mut Genotype<Node<f32>, Ops<f32>>,
+        regression: &Regression<f32>,
+    ) -> f32 {
+        let mut loss = 0_f32;
+        for chromosome in genotype.iter_mut() {
+            let mut graph = Graph::from_nodes(chromosome.genes.clone());
+            loss += self.train(&mut graph, regression);
+
+            for (gene, node) in chromosome.genes.iter_mut().zip(graph.nodes) {
+                gene.value = node.value;
+            }
+        }
+
+        loss
+    }
+
+    fn backward(&self, tape: &mut GraphTape<f32>, regression: &Regression<f32>) -> (f32, Vec<f32>) {
+        let mut positions = vec![None; tape.values.len()];
+        for (position, instruction) in tape.instructions.iter().enumerate() {
+            positions[instruction.slot] = Some(position);
+        }
+
+        regression.with_samples(|samples| {
+            let sequences = regression.split_sequences(samples);
+            let outputs = sequences
+                .iter()
+                .flat_map(|sequence| tape.run_sequence(sequence))
+                .collect::<Vec<Vec<f32>>>();
+
+            let loss_function = regression.get_loss_function();
+            let loss = loss_function.loss(samples, &outputs);
+            let mut seeds = loss_function.gradient(samples, &outputs).into_iter();
+
+            let mut gradients = vec![0_f32; tape.instructions.len()];
+            for sequence in sequences.iter() {
+                tape.reset();
+
+                for chunk in sequence.chunks(self.truncation.unwrap_or(sequence.len()).max(1)) {
+                    let mut history = vec![tape.snapshot()];
+                    let mut deltas = vec![vec![0_f32; tape.values.len()]];
+
+                    for inputs in chunk {
+                        let mut output = vec![0_f32; tape.output_size()];
+                        for _ in 0..tape.settling {
+                            tape.eval_into(inputs, &mut output);
+                            history.push(tape.snapshot());
+                            deltas.push(vec![0_f32; tape.values.len()]);
+                        }
+
+                        let seed = seeds.next().unwrap_or_default();
+                        for (slot, delta) in tape.outputs.iter().zip(seed) {
+                            deltas[history.len() - 1][*slot] += delta;
+                        }
+                    }
+
+                    propagate(tape, &positions, &history, &mut deltas, &mut gradients);
+                }
+            }
+
+            (loss, gradients)
+        })
+    }
+}
+
+fn constant(value: &Ops<f32>) -> f32 {
+    match value {
+        Ops::MutableConst(_, _, value, _, _) => *value,
+        _ => 0_f32,
+    }
+}
+
+fn set_constant(value: &mut Ops<f32>, new_value: f32) {
+    if let Ops::MutableConst(_, _, value, _, _) = value {
+        *value = new_value;
+    }
+}
+
+fn propagate(
+    tape: &GraphTape<f32>,
+    positions: &[Option<usize>],
+    history: &[Vec<f32>],
+    deltas: &mut [Vec<f32>],
+    gradients: &mut [f32],
+) {
+    for pass in (1..history.len()).rev() {
+        for (position, instruction) in tape.instructions.iter().enumerate().rev() {
+            let delta = deltas[pass][instruction.slot];
+            if delta == 0_f32 || instruction.input.is_some() {
+                continue;
+            }
+
+            let (start, end) = instruction.sources;
+            let current = |source: &usize| matches!(positions[*source], Some(at) if at < position);
+            let args = tape.sources[start..end]
+                .iter()
+                .map(|source| match current(source) {
+                    true => history[pass][*source],
+                    false => history[pass - 1][*source],
+                })
+                .collect::<Vec<f32>>();
+
+            gradients[position] += delta * const_derivative(&instruction.value, &args);
+
+            let local = derivative(&instruction.value, &args, history[pass][instruction.slot]);
+            for (source, gradient) in tape.sources[start..end].iter().zip(local) {
+                if current(source) {
+                    deltas[pass][*source] += delta * gradient;
+                } else if pass > 1 && positions[*source].is_some() {
+                    deltas[pass - 1][*source] += delta * gradient;
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,9 @@
 pub mod graph_iterator;
 pub mod graph_reducer;
+pub mod graph_tape;
+pub mod graph_trainer;
 
 pub use graph_iterator::*;
 pub use graph_reducer::*;
+pub use graph_tape::*;
+pub use graph_trainer::*;
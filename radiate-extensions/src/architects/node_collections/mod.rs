@@ -7,6 +7,10 @@ use super::schema::node_types::NodeType;
 pub mod codexes;
 pub mod factories;
 pub mod graph;
+pub mod graph_export;
+pub mod graph_expression;
+pub mod graph_json;
+pub mod innovation;
 pub mod iterators;
 pub mod node_collection;
 pub mod nodes;
@@ -14,6 +18,8 @@ pub mod nodes;
 pub use codexes::*;
 pub use factories::*;
 pub use graph::*;
+pub use graph_expression::*;
+pub use innovation::*;
 pub use iterators::*;
 pub use node_collection::*;
 pub use nodes::*;
@@ -11,6 +11,7 @@ where
 {
     pub id: Uuid,
     pub index: usize,
+    pub innovation: usize,
     pub value: Ops<T>,
     pub arity: Option<u8>,
     pub enabled: bool,
@@ -28,6 +29,7 @@ where
         Self {
             id: Uuid::new_v4(),
             index,
+            innovation: index,
             value,
             arity: None,
             enabled: true,
@@ -98,6 +100,7 @@ where
         Node {
             id: Uuid::new_v4(),
             index: self.index,
+            innovation: self.innovation,
             arity: self.arity.clone(),
             enabled: self.enabled,
             value: self.value.new_instance(),
@@ -112,6 +115,7 @@ where
         Node {
             id: Uuid::new_v4(),
             index: self.index,
+            innovation: self.innovation,
             arity: self.arity.clone(),
             value: allele.clone(),
             enabled: self.enabled,
@@ -147,6 +151,7 @@ where
         Node {
             id: self.id.clone(),
             index: self.index.clone(),
+            innovation: self.innovation,
             arity: self.arity.clone(),
             enabled: self.enabled,
             value: self.value.clone(),
@@ -165,6 +170,7 @@ where
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
             && self.index == other.index
+            && self.innovation == other.innovation
             && self.arity == other.arity
             && self.value == other.value
             && self.direction == other.direction
@@ -182,6 +188,7 @@ where
         Node {
             id: Uuid::new_v4(),
             index: 0,
+            innovation: 0,
             arity: None,
             enabled: true,
             value: Ops::default(),
@@ -36,21 +36,26 @@ where
         self.pending_idx += 1;
     }
 
+    pub fn reset(&mut self) {
+        self.pending_idx = 0;
+        self.args.clear();
+        self.result = None;
+        self.previous_result = None;
+    }
+
     #[inline]
     pub fn eval(&mut self, node: &Node<T>) {
         if self.pending_idx != self.input_size {
             panic!("Tracer is not ready to be evaluated.");
         }
 
-        if !node.enabled {
-            self.result = Some(T::default());
-        }
-
         self.previous_result = self.result.clone();
         self.result = match &node.value {
+            _ if !node.enabled => Some(T::default()),
             Ops::Value(ref value) => Some(value.clone()),
             Ops::Const(_, ref value) => Some(value.clone()),
             Ops::Fn(_, _, ref fn_ptr) => Some(fn_ptr(&self.args)),
+            Ops::Indexed(_, index, ref fn_ptr) => Some(fn_ptr(&self.args, *index)),
             Ops::MutableConst(_, _, ref val, _, fn_ptr) => Some(fn_ptr(&self.args, val)),
             Ops::Var(_, _) => Some(self.args[0].clone()),
         };
@@ -0,0 +1,70 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grid {
+    pub input_size: usize,
+    pub output_size: usize,
+    pub rows: usize,
+    pub columns: usize,
+    pub levels_back: usize,
+}
+
+impl Grid {
+    pub fn new(input_size: usize, output_size: usize, rows: usize, columns: usize) -> Self {
+        Grid {
+            input_size,
+            output_size,
+            rows,
+            columns,
+            levels_back: columns,
+        }
+    }
+
+    pub fn with_levels_back(mut self, levels_back: usize) -> Self {
+        self.levels_back = std::cmp::max(levels_back, 1);
+        self
+    }
+
+    pub fn gate_count(&self) -> usize {
+        self.rows * self.columns
+    }
+
+    pub fn len(&self) -> usize {
+        self.input_size + self.gate_count() + self.output_size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn gate_indices(&self) -> std::ops::Range<usize> {
+        self.input_size..self.input_size + self.gate_count()
+    }
+
+    pub fn output_indices(&self) -> std::ops::Range<usize> {
+        self.input_size + self.gate_count()..self.len()
+    }
+
+    pub fn column(&self, index: usize) -> Option<usize> {
+        match self.gate_indices().contains(&index) {
+            true => Some((index - self.input_size) / self.rows),
+            false => None,
+        }
+    }
+
+    pub fn sources(&self, index: usize) -> Vec<usize> {
+        let inputs = 0..self.input_size;
+
+        if self.output_indices().contains(&index) {
+            return inputs.chain(self.gate_indices()).collect();
+        }
+
+        match self.column(index) {
+            Some(column) => {
+                let first = self.input_size + column.saturating_sub(self.levels_back) * self.rows;
+                let last = self.input_size + column * self.rows;
+
+                inputs.chain(first..last).collect()
+            }
+            None => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layers<T> {
+    pub sizes: Vec<usize>,
+    pub activations: Vec<Option<String>>,
+    pub bias: Option<T>,
+    pub skips: Vec<(usize, usize)>,
+}
+
+impl<T> Layers<T> {
+    pub fn new(sizes: &[usize]) -> Self {
+        if sizes.len() < 2 {
+            panic!("Layers - at least an input and an output layer are required.");
+        }
+
+        if sizes.contains(&0) {
+            panic!("Layers - every layer must have at least one node.");
+        }
+
+        Layers {
+            sizes: sizes.to_vec(),
+            activations: vec![None; sizes.len()],
+            bias: None,
+            skips: Vec::new(),
+        }
+    }
+
+    pub fn activation(mut self, layer: usize, name: &str) -> Self {
+        if layer == 0 || layer >= self.sizes.len() {
+            panic!("Layers - activations can only be set on hidden or output layers.");
+        }
+
+        self.activations[layer] = Some(name.to_string());
+        self
+    }
+
+    pub fn hidden_activation(mut self, name: &str) -> Self {
+        for layer in 1..self.sizes.len() - 1 {
+            self.activations[layer] = Some(name.to_string());
+        }
+
+        self
+    }
+
+    pub fn bias(mut self, value: T) -> Self {
+        self.bias = Some(value);
+        self
+    }
+
+    pub fn skip(mut self, from: usize, to: usize) -> Self {
+        if from + 1 >= to || to >= self.sizes.len() {
+            panic!("Layers - skip connections must join non-adjacent layers in order.");
+        }
+
+        if !self.skips.contains(&(from, to)) {
+            self.skips.push((from, to));
+        }
+
+        self
+    }
+
+    pub fn residual(mut self) -> Self {
+        for from in 0..self.sizes.len().saturating_sub(2) {
+            self = self.skip(from, from + 2);
+        }
+
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+
+    pub fn input_size(&self) -> usize {
+        self.sizes[0]
+    }
+
+    pub fn output_size(&self) -> usize {
+        self.sizes[self.sizes.len() - 1]
+    }
+
+    pub fn connections(&self) -> Vec<(usize, usize)> {
+        let mut connections = (1..self.sizes.len())
+            .map(|to| (to - 1, to))
+            .chain(self.skips.iter().cloned())
+            .collect::<Vec<(usize, usize)>>();
+
+        connections.sort_by_key(|(from, to)| (*to, *from));
+        connections
+    }
+}
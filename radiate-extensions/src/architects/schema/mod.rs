@@ -1,2 +1,4 @@
 pub mod direction;
+pub mod grid;
+pub mod layers;
 pub mod node_types;
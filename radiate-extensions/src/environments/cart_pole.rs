@@ -0,0 +1,84 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::environment::Environment;
+
+const GRAVITY: f32 = 9.8;
+
+pub struct CartPole {
+    pub state: [f32; 4],
+    pub cart_mass: f32,
+    pub pole_mass: f32,
+    pub pole_length: f32,
+    pub force: f32,
+    pub tau: f32,
+    pub track_limit: f32,
+    pub angle_limit: f32,
+}
+
+impl CartPole {
+    pub fn new() -> Self {
+        CartPole {
+            state: [0_f32; 4],
+            cart_mass: 1.0,
+            pole_mass: 0.1,
+            pole_length: 0.5,
+            force: 10.0,
+            tau: 0.02,
+            track_limit: 2.4,
+            angle_limit: 12_f32.to_radians(),
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.state[0].abs() > self.track_limit || self.state[2].abs() > self.angle_limit
+    }
+}
+
+impl Environment for CartPole {
+    fn reset(&mut self, seed: u64) -> Vec<f32> {
+        let mut random = StdRng::seed_from_u64(seed);
+        for value in self.state.iter_mut() {
+            *value = random.gen_range(-0.05..0.05);
+        }
+
+        self.state.to_vec()
+    }
+
+    fn step(&mut self, action: &[f32]) -> (Vec<f32>, f32, bool) {
+        let [x, x_dot, theta, theta_dot] = self.state;
+        let force = self.force * action.first().cloned().unwrap_or(0_f32).clamp(-1.0, 1.0);
+
+        let total_mass = self.cart_mass + self.pole_mass;
+        let pole_moment = self.pole_mass * self.pole_length;
+        let (sin, cos) = theta.sin_cos();
+
+        let temp = (force + pole_moment * theta_dot * theta_dot * sin) / total_mass;
+        let theta_acc = (GRAVITY * sin - cos * temp)
+            / (self.pole_length * (4_f32 / 3_f32 - self.pole_mass * cos * cos / total_mass));
+        let x_acc = temp - pole_moment * theta_acc * cos / total_mass;
+
+        self.state = [
+            x + self.tau * x_dot,
+            x_dot + self.tau * x_acc,
+            theta + self.tau * theta_dot,
+            theta_dot + self.tau * theta_acc,
+        ];
+
+        (self.state.to_vec(), 1_f32, self.failed())
+    }
+
+    fn observation_size(&self) -> usize {
+        4
+    }
+
+    fn action_size(&self) -> usize {
+        1
+    }
+}
+
+impl Default for CartPole {
+    fn default() -> Self {
+        CartPole::new()
+    }
+}
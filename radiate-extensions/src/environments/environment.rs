@@ -0,0 +1,6 @@
+pub trait Environment {
+    fn reset(&mut self, seed: u64) -> Vec<f32>;
+    fn step(&mut self, action: &[f32]) -> (Vec<f32>, f32, bool);
+    fn observation_size(&self) -> usize;
+    fn action_size(&self) -> usize;
+}
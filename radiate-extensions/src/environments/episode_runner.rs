@@ -0,0 +1,96 @@
+use radiate_rust::engines::score::Score;
+
+use super::environment::Environment;
+use crate::architects::node_collections::graph::Graph;
+use crate::architects::node_collections::iterators::GraphReducer;
+
+pub struct EpisodeRunner {
+    pub episodes: usize,
+    pub max_steps: usize,
+    pub seed: u64,
+}
+
+impl EpisodeRunner {
+    pub fn new() -> Self {
+        EpisodeRunner {
+            episodes: 1,
+            max_steps: 500,
+            seed: 0,
+        }
+    }
+
+    pub fn episodes(mut self, episodes: usize) -> Self {
+        self.episodes = episodes.max(1);
+        self
+    }
+
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run<E, F>(&self, environment: &mut E, mut policy: F) -> f32
+    where
+        E: Environment,
+        F: FnMut(&[f32]) -> Vec<f32>,
+    {
+        (0..self.episodes)
+            .map(|episode| self.episode(environment, episode, &mut policy))
+            .sum::<f32>()
+            / self.episodes as f32
+    }
+
+    pub fn run_graph<E>(&self, environment: &mut E, graph: &Graph<f32>) -> f32
+    where
+        E: Environment,
+    {
+        (0..self.episodes)
+            .map(|episode| {
+                let mut reducer = GraphReducer::new(graph);
+                self.episode(environment, episode, &mut |observation| {
+                    reducer.step(observation)
+                })
+            })
+            .sum::<f32>()
+            / self.episodes as f32
+    }
+
+    pub fn score<E>(&self, environment: &mut E, graph: &Graph<f32>) -> Score
+    where
+        E: Environment,
+    {
+        Score::from_f32(self.run_graph(environment, graph))
+    }
+
+    fn episode<E, F>(&self, environment: &mut E, episode: usize, policy: &mut F) -> f32
+    where
+        E: Environment,
+        F: FnMut(&[f32]) -> Vec<f32>,
+    {
+        let mut total = 0_f32;
+        let mut observation = environment.reset(self.seed.wrapping_add(episode as u64));
+
+        for _ in 0..self.max_steps {
+            let (next, reward, done) = environment.step(&policy(&observation));
+            total += reward;
+            observation = next;
+
+            if done {
+                break;
+            }
+        }
+
+        total
+    }
+}
+
+impl Default for EpisodeRunner {
+    fn default() -> Self {
+        EpisodeRunner::new()
+    }
+}
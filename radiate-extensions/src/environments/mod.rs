@@ -0,0 +1,11 @@
+pub mod cart_pole;
+pub mod environment;
+pub mod episode_runner;
+pub mod mountain_car;
+pub mod pole_balancing;
+
+pub use cart_pole::CartPole;
+pub use environment::Environment;
+pub use episode_runner::EpisodeRunner;
+pub use mountain_car::MountainCar;
+pub use pole_balancing::{Pole, PoleBalancing};
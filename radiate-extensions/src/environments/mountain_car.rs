@@ -0,0 +1,73 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::environment::Environment;
+
+pub struct MountainCar {
+    pub position: f32,
+    pub velocity: f32,
+    pub power: f32,
+    pub gravity: f32,
+    pub max_speed: f32,
+    pub min_position: f32,
+    pub max_position: f32,
+    pub goal_position: f32,
+}
+
+impl MountainCar {
+    pub fn new() -> Self {
+        MountainCar {
+            position: -0.5,
+            velocity: 0_f32,
+            power: 0.001,
+            gravity: 0.0025,
+            max_speed: 0.07,
+            min_position: -1.2,
+            max_position: 0.6,
+            goal_position: 0.5,
+        }
+    }
+
+    pub fn observation(&self) -> Vec<f32> {
+        vec![self.position, self.velocity]
+    }
+}
+
+impl Environment for MountainCar {
+    fn reset(&mut self, seed: u64) -> Vec<f32> {
+        let mut random = StdRng::seed_from_u64(seed);
+        self.position = random.gen_range(-0.6..-0.4);
+        self.velocity = 0_f32;
+
+        self.observation()
+    }
+
+    fn step(&mut self, action: &[f32]) -> (Vec<f32>, f32, bool) {
+        let push = action.first().cloned().unwrap_or(0_f32).clamp(-1.0, 1.0);
+
+        self.velocity += push * self.power - (3_f32 * self.position).cos() * self.gravity;
+        self.velocity = self.velocity.clamp(-self.max_speed, self.max_speed);
+        self.position = (self.position + self.velocity).clamp(self.min_position, self.max_position);
+
+        if self.position <= self.min_position && self.velocity < 0_f32 {
+            self.velocity = 0_f32;
+        }
+
+        let done = self.position >= self.goal_position;
+        (self.observation(), -1_f32, done)
+    }
+
+    fn observation_size(&self) -> usize {
+        2
+    }
+
+    fn action_size(&self) -> usize {
+        1
+    }
+}
+
+impl Default for MountainCar {
+    fn default() -> Self {
+        MountainCar::new()
+    }
+}
@@ -0,0 +1,136 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::environment::Environment;
+
+const GRAVITY: f32 = -9.8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pole {
+    pub half_length: f32,
+    pub mass: f32,
+    pub angle: f32,
+    pub velocity: f32,
+}
+
+impl Pole {
+    pub fn new(half_length: f32, mass: f32) -> Self {
+        Pole {
+            half_length,
+            mass,
+            angle: 0_f32,
+            velocity: 0_f32,
+        }
+    }
+}
+
+pub struct PoleBalancing {
+    pub poles: Vec<Pole>,
+    pub position: f32,
+    pub velocity: f32,
+    pub cart_mass: f32,
+    pub force: f32,
+    pub tau: f32,
+    pub track_limit: f32,
+    pub angle_limit: f32,
+}
+
+impl PoleBalancing {
+    pub fn new(poles: Vec<Pole>) -> Self {
+        if poles.is_empty() {
+            panic!("PoleBalancing - at least one pole is required.");
+        }
+
+        PoleBalancing {
+            poles,
+            position: 0_f32,
+            velocity: 0_f32,
+            cart_mass: 1.0,
+            force: 10.0,
+            tau: 0.01,
+            track_limit: 2.4,
+            angle_limit: 36_f32.to_radians(),
+        }
+    }
+
+    pub fn single() -> Self {
+        PoleBalancing::new(vec![Pole::new(0.5, 0.1)])
+    }
+
+    pub fn double() -> Self {
+        PoleBalancing::new(vec![Pole::new(0.5, 0.1), Pole::new(0.05, 0.01)])
+    }
+
+    pub fn observation(&self) -> Vec<f32> {
+        let mut observation = vec![self.position, self.velocity];
+        for pole in self.poles.iter() {
+            observation.push(pole.angle);
+            observation.push(pole.velocity);
+        }
+
+        observation
+    }
+
+    pub fn failed(&self) -> bool {
+        self.position.abs() > self.track_limit
+            || self
+                .poles
+                .iter()
+                .any(|pole| pole.angle.abs() > self.angle_limit)
+    }
+}
+
+impl Environment for PoleBalancing {
+    fn reset(&mut self, seed: u64) -> Vec<f32> {
+        let mut random = StdRng::seed_from_u64(seed);
+        self.position = random.gen_range(-0.05..0.05);
+        self.velocity = 0_f32;
+
+        for (index, pole) in self.poles.iter_mut().enumerate() {
+            pole.angle = match index {
+                0 => random.gen_range(-0.05..0.05),
+                _ => 0_f32,
+            };
+            pole.velocity = 0_f32;
+        }
+
+        self.observation()
+    }
+
+    fn step(&mut self, action: &[f32]) -> (Vec<f32>, f32, bool) {
+        let force = self.force * action.first().cloned().unwrap_or(0_f32).clamp(-1.0, 1.0);
+
+        let mut effective_force = 0_f32;
+        let mut effective_mass = 0_f32;
+        for pole in self.poles.iter() {
+            let (sin, cos) = pole.angle.sin_cos();
+            effective_force += pole.mass * pole.half_length * pole.velocity * pole.velocity * sin
+                + 0.75 * pole.mass * cos * GRAVITY * sin;
+            effective_mass += pole.mass * (1_f32 - 0.75 * cos * cos);
+        }
+
+        let acceleration = (force + effective_force) / (self.cart_mass + effective_mass);
+
+        for pole in self.poles.iter_mut() {
+            let (sin, cos) = pole.angle.sin_cos();
+            let angular_acceleration =
+                -0.75 * (acceleration * cos + GRAVITY * sin) / pole.half_length;
+
+            pole.angle += self.tau * pole.velocity;
+            pole.velocity += self.tau * angular_acceleration;
+        }
+
+        self.position += self.tau * self.velocity;
+        self.velocity += self.tau * acceleration;
+
+        (self.observation(), 1_f32, self.failed())
+    }
+
+    fn observation_size(&self) -> usize {
+        2 + 2 * self.poles.len()
+    }
+
+    fn action_size(&self) -> usize {
+        1
+    }
+}
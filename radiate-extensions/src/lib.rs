@@ -1,9 +1,15 @@
 pub mod alterers;
 pub mod architects;
+pub mod environments;
 pub mod operations;
 pub mod problems;
+pub mod programs;
+pub mod trees;
 
 pub use alterers::*;
 pub use architects::*;
+pub use environments::*;
 pub use operations::*;
 pub use problems::*;
+pub use programs::*;
+pub use trees::*;
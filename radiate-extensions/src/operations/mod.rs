@@ -1,3 +1,7 @@
 pub mod op;
+pub mod op_derivative;
+pub mod op_registry;
 
 pub use op::*;
+pub use op_derivative::*;
+pub use op_registry::*;
@@ -10,11 +10,14 @@ use num_traits::{Float, NumCast};
 const MAX_VALUE: f32 = 1e+5_f32;
 const MIN_VALUE: f32 = -1e+5_f32;
 
+pub type IndexedFn<T> = Arc<dyn Fn(&[T], usize) -> T>;
+
 pub enum Ops<T>
 where
     T: Clone,
 {
     Fn(&'static str, u8, Arc<dyn Fn(&[T]) -> T>),
+    Indexed(&'static str, usize, IndexedFn<T>),
     Value(T),
     Var(String, usize),
     Const(&'static str, T),
@@ -34,6 +37,7 @@ where
     pub fn name(&self) -> &str {
         match self {
             Ops::Fn(name, _, _) => name,
+            Ops::Indexed(name, _, _) => name,
             Ops::Value(_) => "value",
             Ops::Var(name, _) => name,
             Ops::Const(name, _) => name,
@@ -44,6 +48,7 @@ where
     pub fn arity(&self) -> u8 {
         match self {
             Ops::Fn(_, arity, _) => *arity,
+            Ops::Indexed(_, _, _) => 1,
             Ops::Value(_) => 0,
             Ops::Var(_, _) => 0,
             Ops::Const(_, _) => 0,
@@ -54,6 +59,7 @@ where
     pub fn apply(&self, inputs: &[T]) -> T {
         match self {
             Ops::Fn(_, _, op) => op(inputs),
+            Ops::Indexed(_, index, op) => op(inputs, *index),
             Ops::Value(value) => value.clone(),
             Ops::Var(_, index) => inputs[*index].clone(),
             Ops::Const(_, value) => value.clone(),
@@ -64,6 +70,7 @@ where
     pub fn new_instance(&self) -> Ops<T> {
         match self {
             Ops::Fn(name, arity, op) => Ops::Fn(name, *arity, op.clone()),
+            Ops::Indexed(name, index, op) => Ops::Indexed(name, *index, op.clone()),
             Ops::Value(value) => Ops::Value(value.clone()),
             Ops::Var(name, index) => Ops::Var(name.clone(), *index),
             Ops::Const(name, value) => Ops::Const(name, value.clone()),
@@ -85,6 +92,7 @@ where
     fn clone(&self) -> Self {
         match self {
             Ops::Fn(name, arity, op) => Ops::Fn(name, *arity, op.clone()),
+            Ops::Indexed(name, index, op) => Ops::Indexed(name, *index, op.clone()),
             Ops::Value(value) => Ops::Value(value.clone()),
             Ops::Var(name, index) => Ops::Var(name.clone(), *index),
             Ops::Const(name, value) => Ops::Const(name, value.clone()),
@@ -133,6 +141,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Ops::Fn(name, _, _) => write!(f, "Fn: {}", name),
+            Ops::Indexed(name, index, _) => write!(f, "Fn: {}({})", name, index),
             Ops::Value(value) => write!(f, "Val: {:?}", value),
             Ops::Var(name, index) => write!(f, "Var: {}({})", name, index),
             Ops::Const(name, value) => write!(f, "C: {}({:?})", name, value),
@@ -390,6 +399,24 @@ pub fn var<T: Clone>(index: usize) -> Ops<T> {
     Ops::Var(var_name, index)
 }
 
+pub fn softmax<T: Clone + Float>(index: usize) -> Ops<T> {
+    Ops::Indexed(
+        "softmax",
+        index,
+        Arc::new(|inputs: &[T], index: usize| {
+            let max = inputs.iter().fold(T::neg_infinity(), |acc, x| acc.max(*x));
+            let sum = inputs
+                .iter()
+                .fold(T::zero(), |acc, x| acc + (*x - max).exp());
+
+            match inputs.get(index) {
+                Some(input) if sum > T::zero() => (*input - max).exp() / sum,
+                _ => T::zero(),
+            }
+        }),
+    )
+}
+
 pub fn sigmoid() -> Ops<f32> {
     Ops::Fn(
         "sigmoid",
@@ -0,0 +1,137 @@
+use crate::operations::op::Ops;
+
+const STEP: f32 = 1e-3;
+
+pub fn derivative(value: &Ops<f32>, inputs: &[f32], output: f32) -> Vec<f32> {
+    match value {
+        Ops::Fn(name, _, operation) => match fn_derivative(name, inputs, output) {
+            Some(gradient) => gradient,
+            None => numeric(inputs, |args| operation(args)),
+        },
+        Ops::Indexed(name, index, operation) => match *name {
+            "softmax" => inputs
+                .iter()
+                .enumerate()
+                .map(|(other, _)| match other == *index {
+                    true => output * (1_f32 - output),
+                    false => -output * operation(inputs, other),
+                })
+                .collect(),
+            _ => numeric(inputs, |args| operation(args, *index)),
+        },
+        Ops::MutableConst(name, _, weight, _, operation) => match *name {
+            "w" | "weight" => {
+                let mut gradient = vec![0_f32; inputs.len()];
+                if let Some(first) = gradient.first_mut() {
+                    *first = *weight;
+                }
+                gradient
+            }
+            _ => numeric(inputs, |args| operation(args, weight)),
+        },
+        Ops::Var(_, _) | Ops::Value(_) | Ops::Const(_, _) => vec![0_f32; inputs.len()],
+    }
+}
+
+pub fn const_derivative(value: &Ops<f32>, inputs: &[f32]) -> f32 {
+    match value {
+        Ops::MutableConst(name, _, weight, _, operation) => match *name {
+            "w" | "weight" => inputs.first().cloned().unwrap_or_default(),
+            _ => {
+                (operation(inputs, &(weight + STEP)) - operation(inputs, &(weight - STEP)))
+                    / (2_f32 * STEP)
+            }
+        },
+        _ => 0_f32,
+    }
+}
+
+fn fn_derivative(name: &str, inputs: &[f32], output: f32) -> Option<Vec<f32>> {
+    let sum = inputs.iter().sum::<f32>();
+    let each = |gradient: f32| Some(vec![gradient; inputs.len()]);
+    let only = |index: usize, gradient: f32| {
+        let mut result = vec![0_f32; inputs.len()];
+        result[index] = gradient;
+        Some(result)
+    };
+
+    match name {
+        "+" => Some(vec![1_f32, 1_f32]),
+        "-" => Some(vec![1_f32, -1_f32]),
+        "*" | "pow" => Some(vec![inputs[1], inputs[0]]),
+        "/" => match inputs[1] == 0_f32 {
+            true => Some(vec![1_f32, 0_f32]),
+            false => Some(vec![
+                1_f32 / inputs[1],
+                -inputs[0] / (inputs[1] * inputs[1]),
+            ]),
+        },
+        "sum" | "linear" => each(1_f32),
+        "prod" => each(0_f32),
+        "neg" => Some(vec![-1_f32]),
+        "sqrt" => match output > 0_f32 {
+            true => Some(vec![0.5_f32 / output]),
+            false => Some(vec![0_f32]),
+        },
+        "abs" => match inputs[0] == 0_f32 {
+            true => Some(vec![0_f32]),
+            false => Some(vec![inputs[0].signum()]),
+        },
+        "exp" => Some(vec![output]),
+        "log" => match inputs[0] > 0_f32 {
+            true => Some(vec![1_f32 / inputs[0]]),
+            false => Some(vec![0_f32]),
+        },
+        "sin" => Some(vec![inputs[0].cos()]),
+        "cos" => Some(vec![-inputs[0].sin()]),
+        "tan" => Some(vec![1_f32 / (inputs[0].cos() * inputs[0].cos())]),
+        "ceil" | "floor" => Some(vec![0_f32]),
+        ">" => match inputs[0] > inputs[1] {
+            true => Some(vec![1_f32, 0_f32]),
+            false => Some(vec![0_f32, 1_f32]),
+        },
+        "<" => match inputs[0] < inputs[1] {
+            true => Some(vec![1_f32, 0_f32]),
+            false => Some(vec![0_f32, 1_f32]),
+        },
+        "max" => only(first_index(inputs, |x, best| x > best), 1_f32),
+        "min" => only(first_index(inputs, |x, best| x < best), 1_f32),
+        "sigmoid" => each(output * (1_f32 - output)),
+        "relu" => each(if sum > 0_f32 { 1_f32 } else { 0_f32 }),
+        "tanh" => each(1_f32 - output * output),
+        "mish" => {
+            let tanh = sum.tanh();
+            each((1_f32 - tanh * tanh) * (1_f32 + sum.exp()) + tanh * sum.exp())
+        }
+        "l_relu" => each(if sum > 0_f32 { 1_f32 } else { 0.01_f32 }),
+        "soft_plus" => each(1_f32 / (1_f32 + (-sum).exp())),
+        _ => None,
+    }
+}
+
+fn first_index(inputs: &[f32], better: impl Fn(f32, f32) -> bool) -> usize {
+    let mut index = 0;
+    for (i, x) in inputs.iter().enumerate() {
+        if better(*x, inputs[index]) {
+            index = i;
+        }
+    }
+
+    index
+}
+
+fn numeric(inputs: &[f32], function: impl Fn(&[f32]) -> f32) -> Vec<f32> {
+    let mut args = inputs.to_vec();
+
+    (0..inputs.len())
+        .map(|i| {
+            args[i] = inputs[i] + STEP;
+            let upper = function(&args);
+            args[i] = inputs[i] - STEP;
+            let lower = function(&args);
+            args[i] = inputs[i];
+
+            (upper - lower) / (2_f32 * STEP)
+        })
+        .collect()
+}
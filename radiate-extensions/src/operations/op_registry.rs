@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::architects::node_collections::factories::node_factory::NodeFactory;
+use crate::operations::op::{self, Ops};
+
+pub struct OpRegistry<T>
+where
+    T: Clone,
+{
+    pub constructors: HashMap<String, Arc<dyn Fn() -> Ops<T>>>,
+    pub indexed: HashMap<String, Arc<dyn Fn(usize) -> Ops<T>>>,
+}
+
+impl<T> OpRegistry<T>
+where
+    T: Clone,
+{
+    pub fn new() -> Self {
+        OpRegistry {
+            constructors: HashMap::new(),
+            indexed: HashMap::new(),
+        }
+    }
+
+    pub fn register(mut self, name: &str, constructor: impl Fn() -> Ops<T> + 'static) -> Self {
+        self.constructors
+            .insert(name.to_string(), Arc::new(constructor));
+        self
+    }
+
+    pub fn register_indexed(
+        mut self,
+        name: &str,
+        constructor: impl Fn(usize) -> Ops<T> + 'static,
+    ) -> Self {
+        self.indexed.insert(name.to_string(), Arc::new(constructor));
+        self
+    }
+
+    pub fn register_op(self, value: Ops<T>) -> Self
+    where
+        T: 'static,
+    {
+        let name = value.name().to_string();
+        match value {
+            Ops::Indexed(name, _, operation) => self.register_indexed(name, move |index| {
+                Ops::Indexed(name, index, operation.clone())
+            }),
+            value => self.register(&name, move || value.clone()),
+        }
+    }
+
+    pub fn from_factory(factory: &NodeFactory<T>) -> Self
+    where
+        T: PartialEq + Default + 'static,
+    {
+        factory
+            .node_values
+            .values()
+            .flatten()
+            .filter(|value| {
+                matches!(
+                    value,
+                    Ops::Fn(..) | Ops::Indexed(..) | Ops::Const(..) | Ops::MutableConst(..)
+                )
+            })
+            .fold(OpRegistry::new(), |registry, value| {
+                registry.register_op(value.clone())
+            })
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.constructors.contains_key(name) || self.indexed.contains_key(name)
+    }
+
+    pub fn create(&self, name: &str) -> Option<Ops<T>> {
+        self.constructors.get(name).map(|constructor| constructor())
+    }
+
+    pub fn create_indexed(&self, name: &str, index: usize) -> Option<Ops<T>> {
+        self.indexed.get(name).map(|constructor| constructor(index))
+    }
+
+    pub fn extend(mut self, other: OpRegistry<T>) -> Self {
+        self.constructors.extend(other.constructors);
+        self.indexed.extend(other.indexed);
+        self
+    }
+}
+
+impl OpRegistry<f32> {
+    pub fn standard() -> Self {
+        OpRegistry::new()
+            .register("default", Ops::default)
+            .register("+", op::add)
+            .register("-", op::sub)
+            .register("*", op::mul)
+            .register("/", op::div)
+            .register("sum", op::sum)
+            .register("prod", op::prod)
+            .register("neg", op::neg)
+            .register("pow", op::pow)
+            .register("sqrt", op::sqrt)
+            .register("abs", op::abs)
+            .register("exp", op::exp)
+            .register("log", op::log)
+            .register("sin", op::sin)
+            .register("cos", op::cos)
+            .register("tan", op::tan)
+            .register("ceil", op::ceil)
+            .register("floor", op::floor)
+            .register(">", op::gt)
+            .register("<", op::lt)
+            .register("max", op::max)
+            .register("min", op::min)
+            .register("w", op::weight)
+            .register("weight", op::weight)
+            .register("sigmoid", op::sigmoid)
+            .register("relu", op::relu)
+            .register("tanh", op::tanh)
+            .register("linear", op::linear)
+            .register("mish", op::mish)
+            .register("l_relu", op::leaky_relu)
+            .register("soft_plus", op::softplus)
+            .register_indexed("softmax", op::softmax)
+    }
+}
+
+impl<T> Default for OpRegistry<T>
+where
+    T: Clone,
+{
+    fn default() -> Self {
+        OpRegistry::new()
+    }
+}
+
+impl<T> Clone for OpRegistry<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        OpRegistry {
+            constructors: self.constructors.clone(),
+            indexed: self.indexed.clone(),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use super::confusion_matrix::ConfusionMatrix;
+use super::sample_set::{Sample, SampleSet};
+use num_traits::cast::FromPrimitive;
+use num_traits::float::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Decision<T> {
+    Argmax,
+    Threshold(T),
+}
+
+pub struct Classification<T> {
+    pub sample_set: SampleSet<T>,
+    pub decision: Decision<T>,
+}
+
+impl<T> Classification<T>
+where
+    T: Float + FromPrimitive,
+{
+    pub fn new(sample_set: SampleSet<T>, decision: Decision<T>) -> Self {
+        Classification {
+            sample_set,
+            decision,
+        }
+    }
+
+    pub fn from_labels(inputs: Vec<Vec<T>>, labels: Vec<usize>, classes: usize) -> Self {
+        let targets = labels
+            .iter()
+            .map(|label| {
+                if *label >= classes {
+                    panic!(
+                        "Classification - label {} is not one of {} classes.",
+                        label, classes
+                    );
+                }
+
+                (0..classes)
+                    .map(|class| match class == *label {
+                        true => T::one(),
+                        false => T::zero(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Classification::new(SampleSet::from_vecs(inputs, targets), Decision::Argmax)
+    }
+
+    pub fn classes(&self) -> usize {
+        let width = self
+            .sample_set
+            .get_samples()
+            .first()
+            .map(|sample| sample.2.len())
+            .unwrap_or(0);
+
+        match (self.decision, width) {
+            (Decision::Threshold(_), 1) => 2,
+            _ => width,
+        }
+    }
+
+    pub fn predict(&self, output: &[T]) -> usize {
+        match (self.decision, output.len()) {
+            (Decision::Threshold(threshold), 1) => (output[0] >= threshold) as usize,
+            _ => {
+                output
+                    .iter()
+                    .enumerate()
+                    .fold((0, T::neg_infinity()), |best, (index, value)| {
+                        match *value > best.1 {
+                            true => (index, *value),
+                            false => best,
+                        }
+                    })
+                    .0
+            }
+        }
+    }
+
+    pub fn label(&self, sample: &Sample<T>) -> usize {
+        self.predict(&sample.2)
+    }
+
+    pub fn evaluate<F>(&self, mut eval_fn: F) -> (ConfusionMatrix, T)
+    where
+        F: FnMut(&Vec<T>) -> Vec<T>,
+    {
+        let epsilon = T::from_f32(1e-7).unwrap();
+        let mut matrix = ConfusionMatrix::new(self.classes());
+        let mut log_loss = T::zero();
+
+        for sample in self.sample_set.get_samples().iter() {
+            let output = eval_fn(&sample.1);
+            let actual = self.label(sample);
+
+            // A graph with the wrong output width cannot name a class, so it scores as a miss.
+            if output.len() != sample.2.len() {
+                matrix.add(actual, (actual + 1) % matrix.classes());
+                log_loss = log_loss - epsilon.ln();
+                continue;
+            }
+
+            matrix.add(actual, self.predict(&output));
+
+            let probability = match (self.decision, output.len()) {
+                (Decision::Threshold(_), 1) if actual == 0 => T::one() - output[0],
+                (Decision::Threshold(_), 1) => output[0],
+                _ => output.get(actual).cloned().unwrap_or(T::zero()),
+            };
+            log_loss = log_loss - probability.max(epsilon).min(T::one() - epsilon).ln();
+        }
+
+        let count = T::from_usize(self.sample_set.len().max(1)).unwrap();
+        (matrix, log_loss / count)
+    }
+
+    pub fn confusion_matrix<F>(&self, eval_fn: F) -> ConfusionMatrix
+    where
+        F: FnMut(&Vec<T>) -> Vec<T>,
+    {
+        self.evaluate(eval_fn).0
+    }
+
+    pub fn log_loss<F>(&self, eval_fn: F) -> T
+    where
+        F: FnMut(&Vec<T>) -> Vec<T>,
+    {
+        self.evaluate(eval_fn).1
+    }
+
+    pub fn accuracy<F>(&self, eval_fn: F) -> T
+    where
+        F: FnMut(&Vec<T>) -> Vec<T>,
+    {
+        T::from_f32(self.confusion_matrix(eval_fn).accuracy()).unwrap()
+    }
+
+    pub fn error<F>(&self, eval_fn: F) -> T
+    where
+        F: FnMut(&Vec<T>) -> Vec<T>,
+    {
+        T::one() - self.accuracy(eval_fn)
+    }
+
+    pub fn get_samples(&self) -> &[Sample<T>] {
+        self.sample_set.get_samples()
+    }
+}
@@ -0,0 +1,68 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfusionMatrix {
+    pub counts: Vec<Vec<usize>>,
+}
+
+impl ConfusionMatrix {
+    pub fn new(classes: usize) -> Self {
+        ConfusionMatrix {
+            counts: vec![vec![0; classes]; classes],
+        }
+    }
+
+    pub fn add(&mut self, actual: usize, predicted: usize) {
+        self.counts[actual][predicted] += 1;
+    }
+
+    pub fn classes(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().flatten().sum()
+    }
+
+    pub fn correct(&self) -> usize {
+        (0..self.classes())
+            .map(|class| self.counts[class][class])
+            .sum()
+    }
+
+    pub fn accuracy(&self) -> f32 {
+        ratio(self.correct(), self.total())
+    }
+
+    pub fn precision(&self, class: usize) -> f32 {
+        let predicted = self.counts.iter().map(|row| row[class]).sum();
+        ratio(self.counts[class][class], predicted)
+    }
+
+    pub fn recall(&self, class: usize) -> f32 {
+        let actual = self.counts[class].iter().sum();
+        ratio(self.counts[class][class], actual)
+    }
+
+    pub fn f1(&self, class: usize) -> f32 {
+        let precision = self.precision(class);
+        let recall = self.recall(class);
+
+        match precision + recall > 0_f32 {
+            true => 2_f32 * precision * recall / (precision + recall),
+            false => 0_f32,
+        }
+    }
+
+    pub fn macro_f1(&self) -> f32 {
+        match self.classes() {
+            0 => 0_f32,
+            classes => (0..classes).map(|class| self.f1(class)).sum::<f32>() / classes as f32,
+        }
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f32 {
+    match denominator {
+        0 => 0_f32,
+        _ => numerator as f32 / denominator as f32,
+    }
+}
@@ -0,0 +1,100 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Self {
+        Column::Index(index)
+    }
+}
+
+impl From<&str> for Column {
+    fn from(name: &str) -> Self {
+        Column::Name(name.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvFormat {
+    pub delimiter: char,
+    pub header: bool,
+    pub inputs: Vec<Column>,
+    pub targets: Vec<Column>,
+}
+
+impl CsvFormat {
+    pub fn csv() -> Self {
+        CsvFormat {
+            delimiter: ',',
+            header: false,
+            inputs: Vec::new(),
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn tsv() -> Self {
+        CsvFormat {
+            delimiter: '\t',
+            ..CsvFormat::csv()
+        }
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn header(mut self) -> Self {
+        self.header = true;
+        self
+    }
+
+    pub fn inputs<C: Into<Column>>(mut self, columns: impl IntoIterator<Item = C>) -> Self {
+        self.inputs = columns.into_iter().map(|column| column.into()).collect();
+        self
+    }
+
+    pub fn targets<C: Into<Column>>(mut self, columns: impl IntoIterator<Item = C>) -> Self {
+        self.targets = columns.into_iter().map(|column| column.into()).collect();
+        self
+    }
+
+    pub fn resolve(
+        &self,
+        names: &[String],
+        width: usize,
+    ) -> Result<(Vec<usize>, Vec<usize>), String> {
+        let find = |column: &Column| match column {
+            Column::Index(index) if *index < width => Ok(*index),
+            Column::Index(index) => Err(format!("column {} is out of range", index)),
+            Column::Name(name) => names
+                .iter()
+                .position(|header| header == name)
+                .ok_or_else(|| format!("column '{}' is not in the header", name)),
+        };
+
+        let targets = match self.targets.is_empty() {
+            true => vec![width.saturating_sub(1)],
+            false => self
+                .targets
+                .iter()
+                .map(find)
+                .collect::<Result<Vec<usize>, String>>()?,
+        };
+
+        let inputs = match self.inputs.is_empty() {
+            true => (0..width)
+                .filter(|index| !targets.contains(index))
+                .collect(),
+            false => self
+                .inputs
+                .iter()
+                .map(find)
+                .collect::<Result<Vec<usize>, String>>()?,
+        };
+
+        Ok((inputs, targets))
+    }
+}
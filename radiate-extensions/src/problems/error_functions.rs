@@ -1,18 +1,45 @@
 use num_traits::cast::FromPrimitive;
 use num_traits::float::Float;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, Sub, SubAssign};
+use std::sync::Arc;
 
 use super::SampleSet;
 
-pub enum ErrorFunction {
+pub type CustomLoss<T> = Arc<dyn Fn(&[T], &[T]) -> T>;
+
+pub enum ErrorFunction<T> {
     MSE,
     MAE,
+    RMSE,
+    Huber(T),
     CrossEntropy,
+    BinaryCrossEntropy(T),
+    CategoricalCrossEntropy(T),
+    Hinge,
+    // 1 - R², averaged over outputs. R² is a score to maximise, but the trainer and
+    // the engines minimise error, so the unexplained fraction is reported instead.
+    UnexplainedVariance,
+    MAPE,
     Diff,
+    Custom(CustomLoss<T>),
+    Weighted(Vec<T>, Box<ErrorFunction<T>>),
 }
 
-impl ErrorFunction {
-    pub fn calculate<T, F>(&self, samples: &SampleSet<T>, eval_func: &mut F) -> T
+impl<T> ErrorFunction<T> {
+    pub fn custom(loss: impl Fn(&[T], &[T]) -> T + 'static) -> Self {
+        ErrorFunction::Custom(Arc::new(loss))
+    }
+
+    pub fn weighted(self, weights: Vec<T>) -> Self {
+        ErrorFunction::Weighted(weights, Box::new(self))
+    }
+}
+
+impl<T> ErrorFunction<T>
+where
+    T: Float + FromPrimitive,
+{
+    pub fn calculate<F>(&self, samples: &SampleSet<T>, eval_func: &mut F) -> T
     where
         T: Clone
             + PartialEq
@@ -30,58 +57,282 @@ impl ErrorFunction {
             + DivAssign,
         F: FnMut(&Vec<T>) -> Vec<T>,
     {
+        let outputs = samples
+            .get_samples()
+            .iter()
+            .map(|sample| eval_func(&sample.1))
+            .collect::<Vec<Vec<T>>>();
+
+        self.loss(samples, &outputs)
+    }
+
+    pub fn loss(&self, samples: &SampleSet<T>, outputs: &[Vec<T>]) -> T {
+        self.weighted_loss(samples, outputs, &[])
+    }
+
+    pub fn gradient(&self, samples: &SampleSet<T>, outputs: &[Vec<T>]) -> Vec<Vec<T>> {
+        self.weighted_gradient(samples, outputs, &[])
+    }
+
+    fn weighted_loss(&self, samples: &SampleSet<T>, outputs: &[Vec<T>], weights: &[T]) -> T {
+        let count = T::from_usize(samples.get_samples().len().max(1)).unwrap();
+
         match self {
-            ErrorFunction::MSE => {
-                let mut sum = T::default();
-                for sample in samples.get_samples().iter() {
-                    let output = eval_func(&sample.1);
-
-                    for i in 0..sample.2.len() {
-                        let diff = sample.2[i].clone() - output[i].clone();
-                        sum += diff * diff;
-                    }
-                }
+            ErrorFunction::Weighted(inner_weights, inner) => {
+                inner.weighted_loss(samples, outputs, &combine(weights, inner_weights))
+            }
+            ErrorFunction::Custom(loss) => {
+                samples
+                    .get_samples()
+                    .iter()
+                    .zip(outputs.iter())
+                    .fold(T::zero(), |acc, (sample, output)| {
+                        acc + loss(&sample.2, output)
+                    })
+                    / count
+            }
+            ErrorFunction::UnexplainedVariance => {
+                let (residuals, totals) = sums_of_squares(samples, outputs);
+                let total_weight =
+                    (0..residuals.len()).fold(T::zero(), |acc, index| acc + weight(weights, index));
 
-                sum / T::from_usize(samples.get_samples().len()).unwrap()
+                (0..residuals.len()).fold(T::zero(), |acc, index| {
+                    acc + weight(weights, index) * residuals[index] / totals[index]
+                }) / total_weight.max(T::epsilon())
             }
-            ErrorFunction::MAE => {
-                let mut sum = T::default();
-                for sample in samples.get_samples().iter() {
-                    let output = eval_func(&sample.1);
-
-                    for i in 0..sample.2.len() {
-                        let diff = sample.2[i].clone() - output[i].clone();
-                        sum += diff;
+            _ => {
+                let mut sum = T::zero();
+                for (sample, output) in samples.get_samples().iter().zip(outputs.iter()) {
+                    for (index, (expected, actual)) in
+                        sample.2.iter().zip(output.iter()).enumerate()
+                    {
+                        sum = sum + weight(weights, index) * self.term(*expected, *actual);
                     }
                 }
 
-                sum /= T::from_usize(samples.get_samples().len()).unwrap();
-                sum
+                match self {
+                    ErrorFunction::Diff => sum,
+                    ErrorFunction::RMSE => (sum / count).sqrt(),
+                    _ => sum / count,
+                }
             }
-            ErrorFunction::CrossEntropy => {
-                let mut sum = T::default();
-                for sample in samples.get_samples().iter() {
-                    let output = eval_func(&sample.1);
+        }
+    }
 
-                    for i in 0..sample.2.len() {
-                        sum += sample.2[i].clone() * output[i].clone().ln();
-                    }
-                }
+    fn weighted_gradient(
+        &self,
+        samples: &SampleSet<T>,
+        outputs: &[Vec<T>],
+        weights: &[T],
+    ) -> Vec<Vec<T>> {
+        let count = T::from_usize(samples.get_samples().len().max(1)).unwrap();
+        let two = T::from_f32(2_f32).unwrap();
+
+        match self {
+            ErrorFunction::Weighted(inner_weights, inner) => {
+                inner.weighted_gradient(samples, outputs, &combine(weights, inner_weights))
+            }
+            ErrorFunction::Custom(loss) => {
+                let step = T::from_f32(1e-3).unwrap();
+                samples
+                    .get_samples()
+                    .iter()
+                    .zip(outputs.iter())
+                    .map(|(sample, output)| {
+                        let mut args = output.clone();
+                        (0..output.len())
+                            .map(|index| {
+                                args[index] = output[index] + step;
+                                let upper = loss(&sample.2, &args);
+                                args[index] = output[index] - step;
+                                let lower = loss(&sample.2, &args);
+                                args[index] = output[index];
 
-                sum
+                                (upper - lower) / (two * step * count)
+                            })
+                            .collect()
+                    })
+                    .collect()
             }
-            ErrorFunction::Diff => {
-                let mut sum = T::default();
-                for sample in samples.get_samples().iter() {
-                    let output = eval_func(&sample.1);
+            ErrorFunction::UnexplainedVariance => {
+                let (_, totals) = sums_of_squares(samples, outputs);
+                let total_weight = (0..totals.len())
+                    .fold(T::zero(), |acc, index| acc + weight(weights, index))
+                    .max(T::epsilon());
 
-                    for i in 0..sample.2.len() {
-                        sum += (sample.2[i].clone() - output[i].clone()).abs();
+                samples
+                    .get_samples()
+                    .iter()
+                    .zip(outputs.iter())
+                    .map(|(sample, output)| {
+                        sample
+                            .2
+                            .iter()
+                            .zip(output.iter())
+                            .enumerate()
+                            .map(|(index, (expected, actual))| {
+                                -two * weight(weights, index) * (*expected - *actual)
+                                    / (totals[index] * total_weight)
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+            _ => {
+                let scale = match self {
+                    ErrorFunction::Diff => T::one(),
+                    ErrorFunction::RMSE => {
+                        let root = self.weighted_loss(samples, outputs, weights);
+                        match root > T::zero() {
+                            true => count * two * root,
+                            false => T::infinity(),
+                        }
                     }
-                }
+                    _ => count,
+                };
+
+                samples
+                    .get_samples()
+                    .iter()
+                    .zip(outputs.iter())
+                    .map(|(sample, output)| {
+                        sample
+                            .2
+                            .iter()
+                            .zip(output.iter())
+                            .enumerate()
+                            .map(|(index, (expected, actual))| {
+                                weight(weights, index) * self.term_gradient(*expected, *actual)
+                                    / scale
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn term(&self, expected: T, actual: T) -> T {
+        let diff = expected - actual;
+
+        match self {
+            ErrorFunction::MSE | ErrorFunction::RMSE => diff * diff,
+            ErrorFunction::MAE | ErrorFunction::Diff => diff.abs(),
+            ErrorFunction::Huber(delta) => match diff.abs() <= *delta {
+                true => T::from_f32(0.5).unwrap() * diff * diff,
+                false => *delta * (diff.abs() - T::from_f32(0.5).unwrap() * *delta),
+            },
+            ErrorFunction::CrossEntropy => -expected * clip(actual, default_epsilon()).ln(),
+            ErrorFunction::CategoricalCrossEntropy(epsilon) => {
+                -expected * clip(actual, *epsilon).ln()
+            }
+            ErrorFunction::BinaryCrossEntropy(epsilon) => {
+                let actual = clip(actual, *epsilon);
+                -(expected * actual.ln() + (T::one() - expected) * (T::one() - actual).ln())
+            }
+            ErrorFunction::Hinge => (T::one() - expected * actual).max(T::zero()),
+            ErrorFunction::MAPE => {
+                T::from_f32(100_f32).unwrap() * diff.abs() / expected.abs().max(T::epsilon())
+            }
+            _ => T::zero(),
+        }
+    }
+
+    fn term_gradient(&self, expected: T, actual: T) -> T {
+        let diff = expected - actual;
+        let sign = match diff == T::zero() {
+            true => T::zero(),
+            false => diff.signum(),
+        };
 
-                sum
+        match self {
+            ErrorFunction::MSE | ErrorFunction::RMSE => -T::from_f32(2_f32).unwrap() * diff,
+            ErrorFunction::MAE | ErrorFunction::Diff => -sign,
+            ErrorFunction::Huber(delta) => match diff.abs() <= *delta {
+                true => -diff,
+                false => -*delta * sign,
+            },
+            ErrorFunction::CrossEntropy => {
+                cross_entropy_gradient(expected, actual, default_epsilon())
             }
+            ErrorFunction::CategoricalCrossEntropy(epsilon) => {
+                cross_entropy_gradient(expected, actual, *epsilon)
+            }
+            ErrorFunction::BinaryCrossEntropy(epsilon) => {
+                match actual < *epsilon || actual > T::one() - *epsilon {
+                    true => T::zero(),
+                    false => -(expected / actual - (T::one() - expected) / (T::one() - actual)),
+                }
+            }
+            ErrorFunction::Hinge => match T::one() - expected * actual > T::zero() {
+                true => -expected,
+                false => T::zero(),
+            },
+            ErrorFunction::MAPE => {
+                -T::from_f32(100_f32).unwrap() * sign / expected.abs().max(T::epsilon())
+            }
+            _ => T::zero(),
         }
     }
 }
+
+fn default_epsilon<T: Float + FromPrimitive>() -> T {
+    T::from_f32(1e-7).unwrap()
+}
+
+fn clip<T: Float>(value: T, epsilon: T) -> T {
+    value.max(epsilon).min(T::one() - epsilon)
+}
+
+fn cross_entropy_gradient<T: Float>(expected: T, actual: T, epsilon: T) -> T {
+    match actual < epsilon || actual > T::one() - epsilon {
+        true => T::zero(),
+        false => -expected / actual,
+    }
+}
+
+fn weight<T: Float>(weights: &[T], index: usize) -> T {
+    weights.get(index).cloned().unwrap_or(T::one())
+}
+
+fn combine<T: Float>(outer: &[T], inner: &[T]) -> Vec<T> {
+    (0..outer.len().max(inner.len()))
+        .map(|index| weight(outer, index) * weight(inner, index))
+        .collect()
+}
+
+fn sums_of_squares<T: Float + FromPrimitive>(
+    samples: &SampleSet<T>,
+    outputs: &[Vec<T>],
+) -> (Vec<T>, Vec<T>) {
+    let size = samples
+        .get_samples()
+        .first()
+        .map(|sample| sample.2.len())
+        .unwrap_or(0);
+    let count = T::from_usize(samples.get_samples().len().max(1)).unwrap();
+
+    let mut means = vec![T::zero(); size];
+    for sample in samples.get_samples().iter() {
+        for (mean, expected) in means.iter_mut().zip(sample.2.iter()) {
+            *mean = *mean + *expected / count;
+        }
+    }
+
+    let mut residuals = vec![T::zero(); size];
+    let mut totals = vec![T::zero(); size];
+    for (sample, output) in samples.get_samples().iter().zip(outputs.iter()) {
+        for index in 0..size {
+            let residual = sample.2[index] - output[index];
+            let total = sample.2[index] - means[index];
+            residuals[index] = residuals[index] + residual * residual;
+            totals[index] = totals[index] + total * total;
+        }
+    }
+
+    for total in totals.iter_mut() {
+        *total = total.max(T::epsilon());
+    }
+
+    (residuals, totals)
+}
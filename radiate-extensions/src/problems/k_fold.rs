@@ -0,0 +1,47 @@
+use super::sample_set::SampleSet;
+
+pub struct KFold<'a, T> {
+    pub samples: &'a SampleSet<T>,
+    pub folds: usize,
+    pub fold: usize,
+}
+
+impl<'a, T> KFold<'a, T> {
+    pub fn new(samples: &'a SampleSet<T>, folds: usize) -> Self {
+        if folds < 2 || folds > samples.len() {
+            panic!("KFold - folds must be between 2 and the number of samples.");
+        }
+
+        KFold {
+            samples,
+            folds,
+            fold: 0,
+        }
+    }
+}
+
+impl<T> Iterator for KFold<'_, T>
+where
+    T: Clone,
+{
+    type Item = (SampleSet<T>, SampleSet<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.fold >= self.folds {
+            return None;
+        }
+
+        let size = self.samples.len();
+        let start = self.fold * size / self.folds;
+        let end = (self.fold + 1) * size / self.folds;
+        self.fold += 1;
+
+        let train = (0..start).chain(end..size).collect::<Vec<usize>>();
+        let validation = (start..end).collect::<Vec<usize>>();
+
+        Some((
+            self.samples.subset(&train),
+            self.samples.subset(&validation),
+        ))
+    }
+}
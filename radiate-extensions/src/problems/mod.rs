@@ -1,7 +1,21 @@
+pub mod classification;
+pub mod confusion_matrix;
+pub mod csv_format;
 pub mod error_functions;
+pub mod k_fold;
+pub mod normalizer;
+pub mod optimizer;
 pub mod regression;
 pub mod sample_set;
+pub mod time_series;
 
-pub use error_functions::ErrorFunction;
+pub use classification::{Classification, Decision};
+pub use confusion_matrix::ConfusionMatrix;
+pub use csv_format::{Column, CsvFormat};
+pub use error_functions::{CustomLoss, ErrorFunction};
+pub use k_fold::KFold;
+pub use normalizer::{Normalizer, Scaling};
+pub use optimizer::{Optimizer, OptimizerState};
 pub use regression::Regression;
 pub use sample_set::SampleSet;
+pub use time_series::TimeSeries;
@@ -0,0 +1,114 @@
+use num_traits::float::Float;
+
+use super::sample_set::SampleSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scaling {
+    MinMax,
+    ZScore,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalizer<T> {
+    pub scaling: Scaling,
+    pub inputs: Vec<(T, T)>,
+    pub outputs: Vec<(T, T)>,
+}
+
+impl<T> Normalizer<T>
+where
+    T: Float,
+{
+    pub fn fit(samples: &SampleSet<T>, scaling: Scaling) -> Self {
+        Normalizer {
+            scaling,
+            inputs: Normalizer::columns(samples, scaling, |sample| &sample.1),
+            outputs: Normalizer::columns(samples, scaling, |sample| &sample.2),
+        }
+    }
+
+    pub fn transform(&self, samples: &mut SampleSet<T>) {
+        for sample in samples.get_samples_mut().iter_mut() {
+            sample.1 = self.transform_input(&sample.1);
+            sample.2 = self.transform_output(&sample.2);
+        }
+    }
+
+    pub fn transform_input(&self, input: &[T]) -> Vec<T> {
+        Normalizer::apply(&self.inputs, input)
+    }
+
+    pub fn transform_output(&self, output: &[T]) -> Vec<T> {
+        Normalizer::apply(&self.outputs, output)
+    }
+
+    pub fn inverse_input(&self, input: &[T]) -> Vec<T> {
+        Normalizer::invert(&self.inputs, input)
+    }
+
+    pub fn inverse_output(&self, output: &[T]) -> Vec<T> {
+        Normalizer::invert(&self.outputs, output)
+    }
+
+    fn apply(params: &[(T, T)], values: &[T]) -> Vec<T> {
+        values
+            .iter()
+            .zip(params.iter())
+            .map(|(value, (offset, scale))| (*value - *offset) / *scale)
+            .collect()
+    }
+
+    fn invert(params: &[(T, T)], values: &[T]) -> Vec<T> {
+        values
+            .iter()
+            .zip(params.iter())
+            .map(|(value, (offset, scale))| *value * *scale + *offset)
+            .collect()
+    }
+
+    fn columns<F>(samples: &SampleSet<T>, scaling: Scaling, column_fn: F) -> Vec<(T, T)>
+    where
+        F: Fn(&super::sample_set::Sample<T>) -> &Vec<T>,
+    {
+        let width = samples
+            .get_samples()
+            .first()
+            .map(|sample| column_fn(sample).len())
+            .unwrap_or(0);
+        let count = T::from(samples.len().max(1)).unwrap();
+
+        (0..width)
+            .map(|index| {
+                let values = samples
+                    .get_samples()
+                    .iter()
+                    .map(|sample| column_fn(sample)[index])
+                    .collect::<Vec<T>>();
+
+                let (offset, scale) = match scaling {
+                    Scaling::MinMax => {
+                        let min = values
+                            .iter()
+                            .fold(T::infinity(), |acc, value| acc.min(*value));
+                        let max = values
+                            .iter()
+                            .fold(T::neg_infinity(), |acc, value| acc.max(*value));
+                        (min, max - min)
+                    }
+                    Scaling::ZScore => {
+                        let mean = values.iter().fold(T::zero(), |acc, value| acc + *value) / count;
+                        let variance = values.iter().fold(T::zero(), |acc, value| {
+                            acc + (*value - mean) * (*value - mean)
+                        }) / count;
+                        (mean, variance.sqrt())
+                    }
+                };
+
+                match scale > T::epsilon() {
+                    true => (offset, scale),
+                    false => (offset, T::one()),
+                }
+            })
+            .collect()
+    }
+}
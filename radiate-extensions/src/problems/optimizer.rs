@@ -0,0 +1,91 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Optimizer {
+    SGD {
+        learning_rate: f32,
+        momentum: f32,
+    },
+    Adam {
+        learning_rate: f32,
+        beta_one: f32,
+        beta_two: f32,
+        epsilon: f32,
+    },
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OptimizerState {
+    pub step: i32,
+    pub first: Vec<f32>,
+    pub second: Vec<f32>,
+}
+
+impl Optimizer {
+    pub fn sgd(learning_rate: f32) -> Self {
+        Optimizer::SGD {
+            learning_rate,
+            momentum: 0_f32,
+        }
+    }
+
+    pub fn momentum(learning_rate: f32, momentum: f32) -> Self {
+        Optimizer::SGD {
+            learning_rate,
+            momentum,
+        }
+    }
+
+    pub fn adam(learning_rate: f32) -> Self {
+        Optimizer::Adam {
+            learning_rate,
+            beta_one: 0.9,
+            beta_two: 0.999,
+            epsilon: 1e-8,
+        }
+    }
+
+    pub fn update(&self, params: &mut [f32], gradients: &[f32], state: &mut OptimizerState) {
+        if state.first.len() != params.len() {
+            state.step = 0;
+            state.first = vec![0_f32; params.len()];
+            state.second = vec![0_f32; params.len()];
+        }
+
+        state.step += 1;
+
+        match *self {
+            Optimizer::SGD {
+                learning_rate,
+                momentum,
+            } => {
+                for ((param, gradient), velocity) in params
+                    .iter_mut()
+                    .zip(gradients.iter())
+                    .zip(state.first.iter_mut())
+                {
+                    *velocity = momentum * *velocity - learning_rate * gradient;
+                    *param += *velocity;
+                }
+            }
+            Optimizer::Adam {
+                learning_rate,
+                beta_one,
+                beta_two,
+                epsilon,
+            } => {
+                let first_correction = 1_f32 - beta_one.powi(state.step);
+                let second_correction = 1_f32 - beta_two.powi(state.step);
+
+                for (i, (param, gradient)) in params.iter_mut().zip(gradients.iter()).enumerate() {
+                    state.first[i] = beta_one * state.first[i] + (1_f32 - beta_one) * gradient;
+                    state.second[i] =
+                        beta_two * state.second[i] + (1_f32 - beta_two) * gradient * gradient;
+
+                    let first = state.first[i] / first_correction;
+                    let second = state.second[i] / second_correction;
+
+                    *param -= learning_rate * first / (second.sqrt() + epsilon);
+                }
+            }
+        }
+    }
+}
@@ -3,21 +3,30 @@ use super::{error_functions::ErrorFunction, sample_set::SampleSet};
 use num_traits::cast::FromPrimitive;
 use num_traits::float::Float;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::sync::RwLock;
 
 pub struct Regression<T> {
     pub sample_set: SampleSet<T>,
-    pub loss_function: ErrorFunction,
+    pub loss_function: ErrorFunction<T>,
+    pub sequence_length: Option<usize>,
+    pub batch_size: Option<usize>,
+    pub batch: RwLock<Option<SampleSet<T>>>,
+    pub batch_generation: RwLock<Option<i32>>,
 }
 
 impl<T> Regression<T> {
-    pub fn new(sample_set: SampleSet<T>, loss_function: ErrorFunction) -> Self {
+    pub fn new(sample_set: SampleSet<T>, loss_function: ErrorFunction<T>) -> Self {
         Regression {
             sample_set,
             loss_function,
+            sequence_length: None,
+            batch_size: None,
+            batch: RwLock::new(None),
+            batch_generation: RwLock::new(None),
         }
     }
 
-    pub fn from(loss_function: ErrorFunction, samples: Vec<(Vec<T>, Vec<T>)>) -> Self {
+    pub fn from(loss_function: ErrorFunction<T>, samples: Vec<(Vec<T>, Vec<T>)>) -> Self {
         let mut sample_set = SampleSet::new();
         for (input, output) in samples {
             sample_set.add_sample(input, output);
@@ -25,9 +34,99 @@ impl<T> Regression<T> {
         Regression {
             sample_set,
             loss_function,
+            sequence_length: None,
+            batch_size: None,
+            batch: RwLock::new(None),
+            batch_generation: RwLock::new(None),
         }
     }
 
+    pub fn with_sequence_length(mut self, sequence_length: usize) -> Self {
+        self.sequence_length = Some(sequence_length.max(1));
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self
+    where
+        T: Clone,
+    {
+        self.batch_size = Some(batch_size.max(1));
+        *self.batch.write().unwrap() = self.sample_batch();
+        self
+    }
+
+    pub fn next_batch(&self, generation: i32) -> bool
+    where
+        T: Clone,
+    {
+        let mut batch = self.batch.write().unwrap();
+        let mut batch_generation = self.batch_generation.write().unwrap();
+
+        if self.batch_size.is_none() || *batch_generation == Some(generation) {
+            return false;
+        }
+
+        *batch = self.sample_batch();
+        *batch_generation = Some(generation);
+        true
+    }
+
+    pub fn with_samples<R>(&self, samples_fn: impl FnOnce(&SampleSet<T>) -> R) -> R {
+        match self.batch.read().unwrap().as_ref() {
+            Some(batch) => samples_fn(batch),
+            None => samples_fn(&self.sample_set),
+        }
+    }
+
+    pub fn sequences(&self) -> Vec<Vec<Vec<T>>>
+    where
+        T: Clone,
+    {
+        self.with_samples(|samples| self.split_sequences(samples))
+    }
+
+    pub fn split_sequences(&self, samples: &SampleSet<T>) -> Vec<Vec<Vec<T>>>
+    where
+        T: Clone,
+    {
+        let samples = samples.get_samples();
+        let length = self.sequence_length.unwrap_or(samples.len()).max(1);
+
+        samples
+            .chunks(length)
+            .map(|chunk| chunk.iter().map(|sample| sample.1.clone()).collect())
+            .collect()
+    }
+
+    pub fn sequence_error<F>(&self, mut sequence_fn: F) -> T
+    where
+        T: Clone
+            + PartialEq
+            + Default
+            + Add<Output = T>
+            + Div<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + AddAssign
+            + SubAssign
+            + DivAssign
+            + MulAssign
+            + Float
+            + FromPrimitive,
+        F: FnMut(&[Vec<T>]) -> Vec<Vec<T>>,
+    {
+        self.with_samples(|samples| {
+            let outputs = self
+                .split_sequences(samples)
+                .iter()
+                .flat_map(|sequence| sequence_fn(sequence))
+                .collect::<Vec<Vec<T>>>();
+
+            self.loss_function.loss(samples, &outputs)
+        })
+    }
+
     pub fn error<F>(&self, mut error_fn: F) -> T
     where
         T: Clone
@@ -46,15 +145,24 @@ impl<T> Regression<T> {
             + FromPrimitive,
         F: FnMut(&Vec<T>) -> Vec<T>,
     {
-        self.loss_function
-            .calculate(&self.sample_set, &mut error_fn)
+        self.with_samples(|samples| self.loss_function.calculate(samples, &mut error_fn))
+    }
+
+    fn sample_batch(&self) -> Option<SampleSet<T>>
+    where
+        T: Clone,
+    {
+        self.batch_size.map(|size| match self.sequence_length {
+            Some(length) => self.sample_set.sequence_batch(size, length),
+            None => self.sample_set.batch(size),
+        })
     }
 
     pub fn get_samples(&self) -> &[Sample<T>] {
         self.sample_set.get_samples()
     }
 
-    pub fn get_loss_function(&self) -> &ErrorFunction {
+    pub fn get_loss_function(&self) -> &ErrorFunction<T> {
         &self.loss_function
     }
 }
@@ -1,6 +1,17 @@
+use rand::seq::SliceRandom;
+use std::io;
+use std::str::FromStr;
+
+use super::csv_format::CsvFormat;
+use super::k_fold::KFold;
+use super::normalizer::{Normalizer, Scaling};
+use num_traits::float::Float;
+
 #[allow(dead_code)]
+#[derive(Clone)]
 pub struct Sample<T>(pub usize, pub Vec<T>, pub Vec<T>);
 
+#[derive(Clone)]
 pub struct SampleSet<T> {
     samples: Vec<Sample<T>>,
 }
@@ -24,6 +35,80 @@ impl<T> SampleSet<T> {
         SampleSet { samples }
     }
 
+    pub fn from_csv(text: &str, format: &CsvFormat) -> io::Result<Self>
+    where
+        T: FromStr,
+    {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let split = |line: &str| {
+            line.split(format.delimiter)
+                .map(|cell| cell.trim().to_string())
+                .collect::<Vec<String>>()
+        };
+
+        let names = match format.header {
+            true => lines
+                .next()
+                .map(|(_, line)| split(line))
+                .unwrap_or_default(),
+            false => Vec::new(),
+        };
+
+        let mut rows = lines.peekable();
+        let width = match rows.peek() {
+            Some((_, line)) => split(line).len(),
+            None => names.len(),
+        };
+
+        let (inputs, targets) = format.resolve(&names, width).map_err(invalid)?;
+
+        let mut samples = SampleSet::new();
+        for (number, line) in rows {
+            let cells = split(line);
+            if cells.len() != width {
+                return Err(invalid(format!(
+                    "line {} has {} columns, expected {}",
+                    number + 1,
+                    cells.len(),
+                    width
+                )));
+            }
+
+            let parse = |columns: &[usize]| {
+                columns
+                    .iter()
+                    .map(|column| {
+                        cells[*column].parse::<T>().map_err(|_| {
+                            invalid(format!(
+                                "line {} column {} is not a number: '{}'",
+                                number + 1,
+                                column,
+                                cells[*column]
+                            ))
+                        })
+                    })
+                    .collect::<io::Result<Vec<T>>>()
+            };
+
+            samples.add_sample(parse(&inputs)?, parse(&targets)?);
+        }
+
+        Ok(samples)
+    }
+
+    pub fn load_csv(path: &str, format: &CsvFormat) -> io::Result<Self>
+    where
+        T: FromStr,
+    {
+        SampleSet::from_csv(&std::fs::read_to_string(path)?, format)
+    }
+
     pub fn add_sample(&mut self, input: Vec<T>, output: Vec<T>) {
         let index = self.samples.len();
         self.samples.push(Sample(index, input, output));
@@ -40,4 +125,130 @@ impl<T> SampleSet<T> {
     pub fn get_samples_mut(&mut self) -> &mut [Sample<T>] {
         &mut self.samples
     }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn shuffle(&mut self) {
+        self.samples.shuffle(&mut rand::thread_rng());
+        for (index, sample) in self.samples.iter_mut().enumerate() {
+            sample.0 = index;
+        }
+    }
+
+    pub fn subset(&self, indices: &[usize]) -> SampleSet<T>
+    where
+        T: Clone,
+    {
+        SampleSet {
+            samples: indices
+                .iter()
+                .filter_map(|index| self.samples.get(*index))
+                .enumerate()
+                .map(|(index, sample)| Sample(index, sample.1.clone(), sample.2.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn split(&self, fraction: f32) -> (SampleSet<T>, SampleSet<T>)
+    where
+        T: Clone,
+    {
+        let index = (self.len() as f32 * fraction.clamp(0.0, 1.0)).round() as usize;
+        let indices = (0..self.len()).collect::<Vec<usize>>();
+
+        (
+            self.subset(&indices[..index]),
+            self.subset(&indices[index..]),
+        )
+    }
+
+    pub fn train_validation_test(
+        &self,
+        train: f32,
+        validation: f32,
+    ) -> (SampleSet<T>, SampleSet<T>, SampleSet<T>)
+    where
+        T: Clone,
+    {
+        if train < 0.0 || validation < 0.0 || train + validation > 1.0 {
+            panic!("SampleSet - train and validation fractions must sum to at most 1.");
+        }
+
+        let train_end = (self.len() as f32 * train).round() as usize;
+        let validation_end =
+            ((self.len() as f32 * (train + validation)).round() as usize).min(self.len());
+        let indices = (0..self.len()).collect::<Vec<usize>>();
+
+        (
+            self.subset(&indices[..train_end]),
+            self.subset(&indices[train_end..validation_end]),
+            self.subset(&indices[validation_end..]),
+        )
+    }
+
+    pub fn k_fold(&self, folds: usize) -> KFold<'_, T> {
+        KFold::new(self, folds)
+    }
+
+    pub fn batch(&self, size: usize) -> SampleSet<T>
+    where
+        T: Clone,
+    {
+        let mut indices = (0..self.len()).collect::<Vec<usize>>();
+        let (chosen, _) = indices.partial_shuffle(&mut rand::thread_rng(), size.min(self.len()));
+
+        self.subset(chosen)
+    }
+
+    pub fn sequence_batch(&self, size: usize, sequence_length: usize) -> SampleSet<T>
+    where
+        T: Clone,
+    {
+        let length = sequence_length.max(1);
+        let mut starts = (0..self.len()).step_by(length).collect::<Vec<usize>>();
+        let count = size.div_ceil(length).min(starts.len());
+        let (chosen, _) = starts.partial_shuffle(&mut rand::thread_rng(), count);
+        chosen.sort();
+
+        let indices = chosen
+            .iter()
+            .flat_map(|start| *start..(*start + length).min(self.len()))
+            .collect::<Vec<usize>>();
+
+        self.subset(&indices)
+    }
+
+    pub fn batches(&self, size: usize) -> Vec<SampleSet<T>>
+    where
+        T: Clone,
+    {
+        let mut indices = (0..self.len()).collect::<Vec<usize>>();
+        indices.shuffle(&mut rand::thread_rng());
+
+        indices
+            .chunks(size.max(1))
+            .map(|chunk| self.subset(chunk))
+            .collect()
+    }
+
+    pub fn normalize(&mut self, scaling: Scaling) -> Normalizer<T>
+    where
+        T: Float,
+    {
+        let normalizer = Normalizer::fit(self, scaling);
+        normalizer.transform(self);
+        normalizer
+    }
+}
+
+impl<T> Default for SampleSet<T> {
+    fn default() -> Self {
+        SampleSet::new()
+    }
 }
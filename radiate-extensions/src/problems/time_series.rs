@@ -0,0 +1,168 @@
+use super::sample_set::SampleSet;
+use super::{error_functions::ErrorFunction, sample_set::Sample};
+use num_traits::cast::FromPrimitive;
+use num_traits::float::Float;
+
+pub struct TimeSeries<T> {
+    pub series: Vec<Vec<T>>,
+    pub targets: Vec<usize>,
+    pub lag: usize,
+    pub horizon: usize,
+    pub stride: usize,
+    pub loss_function: ErrorFunction<T>,
+    pub sample_set: SampleSet<T>,
+    pub sequences: Vec<Vec<Vec<T>>>,
+}
+
+impl<T> TimeSeries<T>
+where
+    T: Float + FromPrimitive,
+{
+    pub fn new(series: Vec<Vec<T>>, loss_function: ErrorFunction<T>) -> Self {
+        let width = series.first().map(|step| step.len()).unwrap_or(0);
+        if series.iter().any(|step| step.len() != width) {
+            panic!("TimeSeries - every step must have the same number of features.");
+        }
+
+        TimeSeries {
+            series,
+            targets: (0..width).collect(),
+            lag: 1,
+            horizon: 1,
+            stride: 1,
+            loss_function,
+            sample_set: SampleSet::new(),
+            sequences: Vec::new(),
+        }
+        .windowed()
+    }
+
+    pub fn univariate(series: Vec<T>, loss_function: ErrorFunction<T>) -> Self {
+        TimeSeries::new(
+            series.into_iter().map(|value| vec![value]).collect(),
+            loss_function,
+        )
+    }
+
+    pub fn lag(mut self, lag: usize) -> Self {
+        self.lag = lag.max(1);
+        self.windowed()
+    }
+
+    pub fn horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon.max(1);
+        self.windowed()
+    }
+
+    pub fn stride(mut self, stride: usize) -> Self {
+        self.stride = stride.max(1);
+        self.windowed()
+    }
+
+    pub fn targets(mut self, targets: Vec<usize>) -> Self {
+        let width = self.input_size();
+        if targets.is_empty() || targets.iter().any(|target| *target >= width) {
+            panic!("TimeSeries - targets must be non-empty feature indices.");
+        }
+
+        self.targets = targets;
+        self.windowed()
+    }
+
+    pub fn input_size(&self) -> usize {
+        self.series.first().map(|step| step.len()).unwrap_or(0)
+    }
+
+    pub fn output_size(&self) -> usize {
+        self.targets.len() * self.horizon
+    }
+
+    pub fn forecasts<F>(&self, mut sequence_fn: F) -> Vec<Vec<T>>
+    where
+        F: FnMut(&[Vec<T>]) -> Vec<Vec<T>>,
+    {
+        self.sequences
+            .iter()
+            .map(|sequence| sequence_fn(sequence).pop().unwrap_or_default())
+            .collect()
+    }
+
+    pub fn sequence_error<F>(&self, sequence_fn: F) -> T
+    where
+        F: FnMut(&[Vec<T>]) -> Vec<Vec<T>>,
+    {
+        self.loss_function
+            .loss(&self.sample_set, &self.forecasts(sequence_fn))
+    }
+
+    pub fn horizon_errors<F>(&self, sequence_fn: F) -> Vec<T>
+    where
+        F: FnMut(&[Vec<T>]) -> Vec<Vec<T>>,
+    {
+        let forecasts = self.forecasts(sequence_fn);
+        let size = self.targets.len();
+
+        (0..self.horizon)
+            .map(|step| {
+                let range = step * size..(step + 1) * size;
+                let samples = SampleSet::from_samples(
+                    self.sample_set
+                        .get_samples()
+                        .iter()
+                        .map(|sample| {
+                            Sample(sample.0, Vec::new(), sample.2[range.clone()].to_vec())
+                        })
+                        .collect(),
+                );
+                let outputs = forecasts
+                    .iter()
+                    .map(|forecast| forecast.get(range.clone()).unwrap_or_default().to_vec())
+                    .collect::<Vec<Vec<T>>>();
+
+                self.loss_function.loss(&samples, &outputs)
+            })
+            .collect()
+    }
+
+    pub fn error<F>(&self, mut error_fn: F) -> T
+    where
+        F: FnMut(&Vec<T>) -> Vec<T>,
+    {
+        let outputs = self
+            .get_samples()
+            .iter()
+            .map(|sample| error_fn(&sample.1))
+            .collect::<Vec<Vec<T>>>();
+
+        self.loss_function.loss(&self.sample_set, &outputs)
+    }
+
+    pub fn get_samples(&self) -> &[Sample<T>] {
+        self.sample_set.get_samples()
+    }
+
+    pub fn get_loss_function(&self) -> &ErrorFunction<T> {
+        &self.loss_function
+    }
+
+    fn windowed(mut self) -> Self {
+        self.sample_set = SampleSet::new();
+        self.sequences = Vec::new();
+
+        let mut start = 0;
+        while start + self.lag + self.horizon <= self.series.len() {
+            let window = self.series[start..start + self.lag].to_vec();
+            let future = self.series[start + self.lag..start + self.lag + self.horizon]
+                .iter()
+                .flat_map(|step| self.targets.iter().map(move |target| step[*target]))
+                .collect::<Vec<T>>();
+
+            self.sample_set
+                .add_sample(window.iter().flatten().cloned().collect(), future);
+            self.sequences.push(window);
+            start += self.stride;
+        }
+
+        self
+    }
+}
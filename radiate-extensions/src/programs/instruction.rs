@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use radiate_rust::engines::genome::genes::gene::{Gene, Valid};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::operations::op::Ops;
+
+pub struct Instruction<T>
+where
+    T: Clone,
+{
+    pub dst: usize,
+    pub sources: Vec<usize>,
+    pub value: Ops<T>,
+    pub num_registers: usize,
+    pub num_inputs: usize,
+    pub op_set: Arc<Vec<Ops<T>>>,
+}
+
+impl<T> Instruction<T>
+where
+    T: Clone,
+{
+    pub fn new(op_set: Arc<Vec<Ops<T>>>, num_registers: usize, num_inputs: usize) -> Self {
+        let mut random = rand::thread_rng();
+        let value = op_set.choose(&mut random).unwrap().new_instance();
+
+        let mut instruction = Instruction {
+            dst: random.gen_range(0..num_registers),
+            sources: Vec::new(),
+            value,
+            num_registers,
+            num_inputs,
+            op_set,
+        };
+
+        instruction.sources = (0..instruction.value.arity())
+            .map(|_| instruction.random_source())
+            .collect::<Vec<usize>>();
+
+        instruction
+    }
+
+    pub fn dst(&self) -> usize {
+        self.dst
+    }
+
+    pub fn sources(&self) -> &[usize] {
+        &self.sources
+    }
+
+    pub fn value(&self) -> &Ops<T> {
+        &self.value
+    }
+
+    pub fn register_count(&self) -> usize {
+        self.num_registers + self.num_inputs
+    }
+
+    pub fn random_source(&self) -> usize {
+        rand::thread_rng().gen_range(0..self.register_count())
+    }
+
+    pub fn random_dst(&self) -> usize {
+        rand::thread_rng().gen_range(0..self.num_registers)
+    }
+
+    pub fn with_dst(&self, dst: usize) -> Instruction<T> {
+        Instruction {
+            dst,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_source(&self, index: usize, source: usize) -> Instruction<T> {
+        let mut instruction = self.clone();
+        if index < instruction.sources.len() {
+            instruction.sources[index] = source;
+        }
+
+        instruction
+    }
+}
+
+impl<T> Gene<Instruction<T>, Ops<T>> for Instruction<T>
+where
+    T: Clone,
+{
+    fn allele(&self) -> &Ops<T> {
+        &self.value
+    }
+
+    fn new_instance(&self) -> Instruction<T> {
+        Instruction::new(self.op_set.clone(), self.num_registers, self.num_inputs)
+    }
+
+    fn from_allele(&self, allele: &Ops<T>) -> Instruction<T> {
+        let arity = allele.arity() as usize;
+        let mut sources = self.sources.clone();
+
+        sources.truncate(arity);
+        while sources.len() < arity {
+            sources.push(self.random_source());
+        }
+
+        Instruction {
+            dst: self.dst,
+            sources,
+            value: allele.clone(),
+            num_registers: self.num_registers,
+            num_inputs: self.num_inputs,
+            op_set: self.op_set.clone(),
+        }
+    }
+}
+
+impl<T> Valid for Instruction<T>
+where
+    T: Clone,
+{
+    fn is_valid(&self) -> bool {
+        self.dst < self.num_registers
+            && self.sources.len() == self.value.arity() as usize
+            && self
+                .sources
+                .iter()
+                .all(|source| *source < self.register_count())
+    }
+}
+
+impl<T> Clone for Instruction<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Instruction {
+            dst: self.dst,
+            sources: self.sources.clone(),
+            value: self.value.clone(),
+            num_registers: self.num_registers,
+            num_inputs: self.num_inputs,
+            op_set: self.op_set.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for Instruction<T>
+where
+    T: Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.dst == other.dst && self.sources == other.sources && self.value == other.value
+    }
+}
+
+impl<T> std::fmt::Debug for Instruction<T>
+where
+    T: Clone + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sources = self
+            .sources
+            .iter()
+            .map(|source| match *source < self.num_registers {
+                true => format!("r{}", source),
+                false => format!("x{}", source - self.num_registers),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, "r{} = {:?}({})", self.dst, self.value, sources)
+    }
+}
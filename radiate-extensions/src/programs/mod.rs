@@ -0,0 +1,7 @@
+pub mod instruction;
+pub mod program;
+pub mod program_codex;
+
+pub use instruction::Instruction;
+pub use program::Program;
+pub use program_codex::ProgramCodex;
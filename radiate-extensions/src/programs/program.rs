@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+
+use crate::operations::op::Ops;
+
+use super::instruction::Instruction;
+
+pub struct Program<T>
+where
+    T: Clone,
+{
+    pub instructions: Vec<Instruction<T>>,
+    pub num_registers: usize,
+    pub num_inputs: usize,
+    pub num_outputs: usize,
+}
+
+impl<T> Program<T>
+where
+    T: Clone + Default,
+{
+    pub fn new(
+        instructions: Vec<Instruction<T>>,
+        num_registers: usize,
+        num_inputs: usize,
+        num_outputs: usize,
+    ) -> Self {
+        Program {
+            instructions,
+            num_registers,
+            num_inputs,
+            num_outputs,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Instruction<T>> {
+        self.instructions.iter()
+    }
+
+    pub fn run(&self, inputs: &[T]) -> Vec<T> {
+        if inputs.len() != self.num_inputs {
+            panic!(
+                "Program - expected {} inputs but received {}.",
+                self.num_inputs,
+                inputs.len()
+            );
+        }
+
+        let mut registers = vec![T::default(); self.num_registers];
+        registers.extend(inputs.iter().cloned());
+
+        for instruction in self.instructions.iter() {
+            let result = match &instruction.value {
+                Ops::Var(_, index) if *index < self.num_inputs => {
+                    registers[self.num_registers + *index].clone()
+                }
+                Ops::Var(name, index) => panic!(
+                    "Program - input {} ({}) is out of range for {} inputs.",
+                    index, name, self.num_inputs
+                ),
+                op => {
+                    let operands = instruction
+                        .sources
+                        .iter()
+                        .map(|source| registers[*source].clone())
+                        .collect::<Vec<T>>();
+
+                    op.apply(&operands)
+                }
+            };
+
+            registers[instruction.dst] = result;
+        }
+
+        registers.truncate(self.num_outputs);
+        registers
+    }
+
+    pub fn effective_indices(&self) -> Vec<usize> {
+        let mut live = (0..self.num_outputs).collect::<HashSet<usize>>();
+        let mut effective = Vec::new();
+
+        for (index, instruction) in self.instructions.iter().enumerate().rev() {
+            if !live.remove(&instruction.dst) {
+                continue;
+            }
+
+            for source in instruction.sources.iter() {
+                if *source < self.num_registers {
+                    live.insert(*source);
+                }
+            }
+
+            effective.push(index);
+        }
+
+        effective.reverse();
+        effective
+    }
+
+    pub fn eliminate_introns(&self) -> Program<T> {
+        Program {
+            instructions: self
+                .effective_indices()
+                .into_iter()
+                .map(|index| self.instructions[index].clone())
+                .collect::<Vec<Instruction<T>>>(),
+            num_registers: self.num_registers,
+            num_inputs: self.num_inputs,
+            num_outputs: self.num_outputs,
+        }
+    }
+}
+
+impl<T> Clone for Program<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Program {
+            instructions: self.instructions.clone(),
+            num_registers: self.num_registers,
+            num_inputs: self.num_inputs,
+            num_outputs: self.num_outputs,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Program<T>
+where
+    T: Clone + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for instruction in self.instructions.iter() {
+            writeln!(f, "{:?}", instruction)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use radiate_rust::engines::codexes::Codex;
+use radiate_rust::engines::genome::chromosome::Chromosome;
+use radiate_rust::engines::genome::genotype::Genotype;
+use rand::Rng;
+
+use crate::operations::op::{self, Ops};
+
+use super::instruction::Instruction;
+use super::program::Program;
+
+pub struct ProgramCodex<T>
+where
+    T: Clone,
+{
+    pub num_inputs: usize,
+    pub num_outputs: usize,
+    pub num_registers: usize,
+    pub min_length: usize,
+    pub max_length: usize,
+    pub op_set: Arc<Vec<Ops<T>>>,
+}
+
+impl<T> ProgramCodex<T>
+where
+    T: Clone + Default,
+{
+    pub fn new(num_inputs: usize, num_outputs: usize, op_set: Vec<Ops<T>>) -> Self {
+        if op_set.is_empty() {
+            panic!("A program codex needs at least one operation.");
+        }
+
+        ProgramCodex {
+            num_inputs,
+            num_outputs,
+            num_registers: num_outputs + num_inputs,
+            min_length: 5,
+            max_length: 30,
+            op_set: Arc::new(op_set),
+        }
+    }
+
+    pub fn with_registers(mut self, num_registers: usize) -> Self {
+        self.num_registers = std::cmp::max(num_registers, self.num_outputs);
+        self
+    }
+
+    pub fn with_length(mut self, min_length: usize, max_length: usize) -> Self {
+        self.min_length = std::cmp::max(std::cmp::min(min_length, max_length), 1);
+        self.max_length = std::cmp::max(max_length, self.min_length);
+        self
+    }
+}
+
+impl ProgramCodex<f32> {
+    pub fn regression(num_inputs: usize, num_outputs: usize) -> Self {
+        ProgramCodex::new(
+            num_inputs,
+            num_outputs,
+            vec![op::add(), op::sub(), op::mul(), op::div(), op::weight()],
+        )
+    }
+}
+
+impl<T> Codex<Instruction<T>, Ops<T>, Program<T>> for ProgramCodex<T>
+where
+    T: Clone + Default,
+{
+    fn encode(&self) -> Genotype<Instruction<T>, Ops<T>> {
+        let length = rand::thread_rng().gen_range(self.min_length..=self.max_length);
+
+        Genotype {
+            chromosomes: vec![Chromosome::from_genes(
+                (0..length)
+                    .map(|_| {
+                        Instruction::new(self.op_set.clone(), self.num_registers, self.num_inputs)
+                    })
+                    .collect::<Vec<Instruction<T>>>(),
+            )
+            .with_length_bounds(self.min_length, self.max_length)],
+        }
+    }
+
+    fn decode(&self, genotype: &Genotype<Instruction<T>, Ops<T>>) -> Program<T> {
+        Program::new(
+            genotype
+                .iter()
+                .next()
+                .unwrap()
+                .iter()
+                .cloned()
+                .collect::<Vec<Instruction<T>>>(),
+            self.num_registers,
+            self.num_inputs,
+            self.num_outputs,
+        )
+    }
+}
@@ -0,0 +1,7 @@
+pub mod tree;
+pub mod tree_codex;
+pub mod tree_node;
+
+pub use tree::Tree;
+pub use tree_codex::TreeCodex;
+pub use tree_node::TreeNode;
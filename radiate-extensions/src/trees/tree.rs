@@ -0,0 +1,77 @@
+use super::tree_node::TreeNode;
+
+pub struct Tree<T>
+where
+    T: Clone,
+{
+    pub roots: Vec<TreeNode<T>>,
+}
+
+impl<T> Tree<T>
+where
+    T: Clone,
+{
+    pub fn new(roots: Vec<TreeNode<T>>) -> Self {
+        Tree { roots }
+    }
+
+    pub fn roots(&self) -> &[TreeNode<T>] {
+        &self.roots
+    }
+
+    pub fn size(&self) -> usize {
+        self.roots.iter().map(|root| root.size()).sum()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.roots
+            .iter()
+            .map(|root| root.depth())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn eval(&self, inputs: &[T]) -> Vec<T> {
+        self.roots
+            .iter()
+            .map(|root| root.eval(inputs))
+            .collect::<Vec<T>>()
+    }
+}
+
+impl<T> Clone for Tree<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Tree {
+            roots: self.roots.clone(),
+        }
+    }
+}
+
+impl<T> std::fmt::Display for Tree<T>
+where
+    T: Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (index, root) in self.roots.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{}", root)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> std::fmt::Debug for Tree<T>
+where
+    T: Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
@@ -0,0 +1,71 @@
+use radiate_rust::engines::codexes::Codex;
+use radiate_rust::engines::genome::chromosome::Chromosome;
+use radiate_rust::engines::genome::genotype::Genotype;
+use rand::{random, Rng};
+
+use crate::architects::node_collections::node_factory::NodeFactory;
+use crate::operations::op::Ops;
+
+use super::tree::Tree;
+use super::tree_node::TreeNode;
+
+pub struct TreeCodex<'a, T>
+where
+    T: Clone + PartialEq + Default,
+{
+    pub num_outputs: usize,
+    pub min_depth: usize,
+    pub max_depth: usize,
+    pub factory: &'a NodeFactory<T>,
+}
+
+impl<'a, T> TreeCodex<'a, T>
+where
+    T: Clone + PartialEq + Default,
+{
+    pub fn new(num_outputs: usize, max_depth: usize, factory: &'a NodeFactory<T>) -> Self {
+        TreeCodex {
+            num_outputs,
+            min_depth: std::cmp::min(2, max_depth),
+            max_depth,
+            factory,
+        }
+    }
+
+    pub fn with_min_depth(mut self, min_depth: usize) -> Self {
+        self.min_depth = std::cmp::min(min_depth, self.max_depth);
+        self
+    }
+
+    pub fn ramped_half_and_half(&self) -> TreeNode<T> {
+        let depth = rand::thread_rng().gen_range(self.min_depth..=self.max_depth);
+        TreeNode::grow(self.factory, depth, random::<bool>())
+    }
+}
+
+impl<'a, T> Codex<TreeNode<T>, Ops<T>, Tree<T>> for TreeCodex<'a, T>
+where
+    T: Clone + PartialEq + Default,
+{
+    fn encode(&self) -> Genotype<TreeNode<T>, Ops<T>> {
+        Genotype {
+            chromosomes: vec![Chromosome::from_genes(
+                (0..self.num_outputs)
+                    .map(|_| self.ramped_half_and_half())
+                    .collect::<Vec<TreeNode<T>>>(),
+            )],
+        }
+    }
+
+    fn decode(&self, genotype: &Genotype<TreeNode<T>, Ops<T>>) -> Tree<T> {
+        Tree::new(
+            genotype
+                .iter()
+                .next()
+                .unwrap()
+                .iter()
+                .cloned()
+                .collect::<Vec<TreeNode<T>>>(),
+        )
+    }
+}
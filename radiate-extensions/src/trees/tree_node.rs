@@ -0,0 +1,288 @@
+use radiate_rust::engines::genome::genes::gene::{Gene, Valid};
+use rand::seq::SliceRandom;
+use rand::{random, Rng};
+
+use crate::architects::node_collections::node_factory::NodeFactory;
+use crate::architects::schema::node_types::NodeType;
+use crate::operations::op::Ops;
+
+pub struct TreeNode<T>
+where
+    T: Clone,
+{
+    pub value: Ops<T>,
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T>
+where
+    T: Clone,
+{
+    pub fn new(value: Ops<T>, children: Vec<TreeNode<T>>) -> Self {
+        TreeNode { value, children }
+    }
+
+    pub fn leaf(value: Ops<T>) -> Self {
+        TreeNode {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn value(&self) -> &Ops<T> {
+        &self.value
+    }
+
+    pub fn children(&self) -> &[TreeNode<T>] {
+        &self.children
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn size(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(|child| child.size())
+            .sum::<usize>()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.children
+            .iter()
+            .map(|child| child.depth() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn eval(&self, inputs: &[T]) -> T {
+        if self.children.is_empty() {
+            return self.value.apply(inputs);
+        }
+
+        let values = self
+            .children
+            .iter()
+            .map(|child| child.eval(inputs))
+            .collect::<Vec<T>>();
+
+        self.value.apply(&values)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&TreeNode<T>> {
+        if index == 0 {
+            return Some(self);
+        }
+
+        let mut offset = 1;
+        for child in self.children.iter() {
+            let size = child.size();
+            if index < offset + size {
+                return child.get(index - offset);
+            }
+
+            offset += size;
+        }
+
+        None
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut TreeNode<T>> {
+        if index == 0 {
+            return Some(self);
+        }
+
+        let mut offset = 1;
+        for child in self.children.iter_mut() {
+            let size = child.size();
+            if index < offset + size {
+                return child.get_mut(index - offset);
+            }
+
+            offset += size;
+        }
+
+        None
+    }
+
+    pub fn level(&self, index: usize) -> Option<usize> {
+        if index == 0 {
+            return Some(0);
+        }
+
+        let mut offset = 1;
+        for child in self.children.iter() {
+            let size = child.size();
+            if index < offset + size {
+                return child.level(index - offset).map(|level| level + 1);
+            }
+
+            offset += size;
+        }
+
+        None
+    }
+
+    pub fn replace(&self, index: usize, subtree: TreeNode<T>) -> TreeNode<T> {
+        let mut result = self.clone();
+        if let Some(node) = result.get_mut(index) {
+            *node = subtree;
+        }
+
+        result
+    }
+
+    pub fn random_index(&self) -> usize {
+        rand::thread_rng().gen_range(0..self.size())
+    }
+
+    pub fn grow(factory: &NodeFactory<T>, depth: usize, full: bool) -> TreeNode<T>
+    where
+        T: PartialEq + Default,
+    {
+        let functions = factory
+            .node_values
+            .get(&NodeType::Gate)
+            .map(|values| values.as_slice())
+            .unwrap_or(&[]);
+        let terminals = factory
+            .node_values
+            .get(&NodeType::Input)
+            .map(|values| values.as_slice())
+            .unwrap_or(&[]);
+
+        if terminals.is_empty() {
+            panic!("A tree needs at least one input value to use as a terminal.");
+        }
+
+        let total = (functions.len() + terminals.len()) as f32;
+        let pick_function = depth > 0
+            && !functions.is_empty()
+            && (full || random::<f32>() < functions.len() as f32 / total);
+
+        if !pick_function {
+            return TreeNode::random_terminal(factory);
+        }
+
+        let value = functions
+            .choose(&mut rand::thread_rng())
+            .unwrap()
+            .new_instance();
+
+        let children = (0..value.arity())
+            .map(|_| TreeNode::grow(factory, depth - 1, full))
+            .collect::<Vec<TreeNode<T>>>();
+
+        TreeNode::new(value, children)
+    }
+
+    pub fn random_terminal(factory: &NodeFactory<T>) -> TreeNode<T>
+    where
+        T: PartialEq + Default,
+    {
+        let terminals = factory
+            .node_values
+            .get(&NodeType::Input)
+            .expect("A tree needs at least one input value to use as a terminal.");
+
+        TreeNode::leaf(
+            terminals
+                .choose(&mut rand::thread_rng())
+                .unwrap()
+                .new_instance(),
+        )
+    }
+}
+
+impl<T> Gene<TreeNode<T>, Ops<T>> for TreeNode<T>
+where
+    T: Clone,
+{
+    fn allele(&self) -> &Ops<T> {
+        &self.value
+    }
+
+    fn new_instance(&self) -> TreeNode<T> {
+        TreeNode {
+            value: self.value.new_instance(),
+            children: self
+                .children
+                .iter()
+                .map(|child| child.new_instance())
+                .collect::<Vec<TreeNode<T>>>(),
+        }
+    }
+
+    fn from_allele(&self, allele: &Ops<T>) -> TreeNode<T> {
+        TreeNode {
+            value: allele.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<T> Valid for TreeNode<T>
+where
+    T: Clone,
+{
+    fn is_valid(&self) -> bool {
+        let arity_matches = match self.value {
+            Ops::Fn(_, arity, _) | Ops::MutableConst(_, arity, _, _, _) => {
+                self.children.len() == arity as usize
+            }
+            _ => self.children.is_empty(),
+        };
+
+        arity_matches && self.children.iter().all(|child| child.is_valid())
+    }
+}
+
+impl<T> Clone for TreeNode<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        TreeNode {
+            value: self.value.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for TreeNode<T>
+where
+    T: Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.children == other.children
+    }
+}
+
+impl<T> std::fmt::Display for TreeNode<T>
+where
+    T: Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.children.is_empty() {
+            return write!(f, "{}", self.value);
+        }
+
+        write!(f, "({}", self.value)?;
+        for child in self.children.iter() {
+            write!(f, " {}", child)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<T> std::fmt::Debug for TreeNode<T>
+where
+    T: Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
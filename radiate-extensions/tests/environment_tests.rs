@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+
+    use radiate_extensions::*;
+
+    #[test]
+    fn environments_reset_deterministically_from_seed() {
+        let mut cart_pole = CartPole::new();
+        let first = cart_pole.reset(7);
+        assert_eq!(first.len(), cart_pole.observation_size());
+        assert_eq!(cart_pole.reset(7), first);
+        assert_ne!(cart_pole.reset(8), first);
+
+        let mut double = PoleBalancing::double();
+        assert_eq!(double.reset(3).len(), 6);
+        assert_eq!(PoleBalancing::single().observation_size(), 4);
+
+        let mut mountain_car = MountainCar::new();
+        let start = mountain_car.reset(11);
+        assert!(start[0] >= -0.6 && start[0] < -0.4);
+        assert_eq!(start[1], 0.0);
+    }
+
+    #[test]
+    fn pole_balancing_rewards_controllers_that_stay_upright() {
+        let runner = EpisodeRunner::new().episodes(3).max_steps(500).seed(1);
+
+        let idle = runner.run(&mut CartPole::new(), |_| vec![0.0]);
+        let balanced = runner.run(&mut CartPole::new(), |state| {
+            vec![state[0] * 0.1 + state[1] * 0.5 + state[2] * 10.0 + state[3] * 2.0]
+        });
+        assert!(idle < 500.0);
+        assert_eq!(balanced, 500.0);
+
+        for mut environment in [PoleBalancing::single(), PoleBalancing::double()] {
+            let idle = runner.run(&mut environment, |_| vec![0.0]);
+            assert!(idle > 0.0 && idle < 500.0);
+        }
+
+        let single = runner.run(&mut PoleBalancing::single(), |state| {
+            vec![state[0] * 0.1 + state[1] * 0.5 + state[2] * 10.0 + state[3] * 2.0]
+        });
+        assert_eq!(single, 500.0);
+    }
+
+    #[test]
+    fn mountain_car_rewards_reaching_the_goal_quickly() {
+        let runner = EpisodeRunner::new().episodes(2).max_steps(200);
+
+        let idle = runner.run(&mut MountainCar::new(), |_| vec![0.0]);
+        let pumping = runner.run(&mut MountainCar::new(), |state| {
+            vec![match state[1] < 0.0 {
+                true => -1.0,
+                false => 1.0,
+            }]
+        });
+
+        assert_eq!(idle, -200.0);
+        assert!(pumping > -200.0);
+    }
+
+    #[test]
+    fn episode_runner_scores_graph_controllers() {
+        let factory = NodeFactory::<f32>::regression(4);
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).weighted_acyclic(4, 1);
+        let runner = EpisodeRunner::new().episodes(4).max_steps(100).seed(5);
+
+        let score = runner.score(&mut CartPole::new(), &graph);
+        assert!(score.as_float() >= 1.0 && score.as_float() <= 100.0);
+        assert_eq!(
+            runner.run_graph(&mut CartPole::new(), &graph),
+            score.as_float()
+        );
+    }
+}
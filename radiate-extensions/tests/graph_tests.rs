@@ -87,4 +87,901 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn cgp_graph_respects_levels_back() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let grid = Grid::new(2, 1, 2, 5).with_levels_back(2);
+
+        for _ in 0..20 {
+            let graph = Architect::<Graph<f32>, f32>::new(&factory).cgp(&grid);
+
+            assert_eq!(graph.len(), grid.len());
+            assert!(graph.is_valid());
+
+            for index in grid.gate_indices().chain(grid.output_indices()) {
+                let sources = grid.sources(index);
+                let node = graph.get(index).unwrap();
+
+                assert!(node.incoming.iter().all(|source| sources.contains(source)));
+            }
+        }
+    }
+
+    #[test]
+    fn cgp_mutator_keeps_graph_valid() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let grid = Grid::new(2, 2, 3, 4).with_levels_back(1);
+        let codex = GraphCodex::from_grid(grid, &factory);
+        let mutator = CgpMutator::new(factory.clone(), grid, 0.3, 0.3);
+
+        for _ in 0..50 {
+            let mut chromosome = codex.encode().get_chromosome(0).clone();
+            mutator.mutate_chromosome(&mut chromosome, 0);
+
+            let graph = Graph::from_nodes(chromosome.iter().cloned().collect());
+            assert!(graph.is_valid());
+
+            let active = graph.active_nodes();
+            for index in grid.output_indices() {
+                assert!(active.contains(&index));
+            }
+
+            let mut reducer = GraphReducer::new(&graph);
+            assert_eq!(reducer.reduce(&[1.0, 2.0]).len(), 2);
+        }
+    }
+
+    #[test]
+    fn structural_mutations_keep_graph_valid_and_acyclic() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let mutator = GraphMutator::<f32>::new(factory.clone(), vec![]);
+
+        for _ in 0..20 {
+            let mut nodes = Architect::<Graph<f32>, f32>::new(&factory)
+                .weighted_acyclic(2, 2)
+                .into_iter()
+                .collect::<Vec<Node<f32>>>();
+
+            for node_type in [NodeType::Aggregate, NodeType::Weight, NodeType::Gate].iter() {
+                if let Some(grown) = mutator.insert_forward_node(&nodes, node_type) {
+                    nodes = grown;
+                }
+            }
+
+            for step in 0..20 {
+                let mutated = match step % 5 {
+                    0 => mutator.remove_node(&nodes),
+                    1 => mutator.remove_edge(&nodes),
+                    2 => mutator.toggle(&nodes, &[NodeType::Gate, NodeType::Aggregate]),
+                    3 => mutator.toggle_edge(&nodes),
+                    _ => mutator.rewire(&nodes),
+                };
+
+                if let Some(mutated) = mutated {
+                    nodes = mutated;
+                }
+
+                let graph = Graph::from_nodes(nodes.clone());
+                assert!(graph.is_valid());
+
+                for node in graph.iter() {
+                    assert_eq!(
+                        node.index,
+                        graph.iter().position(|n| n.id == node.id).unwrap()
+                    );
+                    for target in node.outgoing.iter() {
+                        assert!(!would_create_cycle(graph.get_nodes(), node.index, *target));
+                    }
+                }
+
+                let mut reducer = GraphReducer::new(&graph);
+                assert_eq!(reducer.reduce(&[1.0, 2.0]).len(), 2);
+            }
+        }
+    }
+
+    #[test]
+    fn toggle_edge_drops_a_single_aggregate_input() {
+        let mut graph = Graph::<f32>::from_nodes(vec![
+            Node::new(0, NodeType::Input, op::var(0)),
+            Node::new(1, NodeType::Input, op::var(1)),
+            Node::new(2, NodeType::Weight, op::linear()),
+            Node::new(3, NodeType::Weight, op::linear()),
+            Node::new(4, NodeType::Output, op::max()),
+        ]);
+        graph.attach(0, 2).attach(1, 3).attach(2, 4).attach(3, 4);
+
+        let mutator = GraphMutator::<f32>::new(NodeFactory::<f32>::regression(2), vec![]);
+        let toggled = Graph::from_nodes(mutator.toggle_edge(graph.get_nodes()).unwrap());
+        let disabled = toggled.iter().find(|node| !node.enabled).unwrap().index;
+
+        let expected = match disabled {
+            2 => -3.0,
+            _ => -2.0,
+        };
+
+        assert_eq!(GraphReducer::new(&graph).reduce(&[-2.0, -3.0]), vec![-2.0]);
+        assert_eq!(
+            GraphReducer::new(&toggled).reduce(&[-2.0, -3.0]),
+            vec![expected]
+        );
+        assert_eq!(toggled.compile().eval(&[-2.0, -3.0]), vec![expected]);
+        assert!(mutator.toggle_edge(toggled.get_nodes()).is_some());
+    }
+
+    #[test]
+    fn innovations_align_matching_structure() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let architect = Architect::<Graph<f32>, f32>::new(&factory);
+
+        let one = architect
+            .weighted_acyclic(2, 2)
+            .into_iter()
+            .collect::<Vec<Node<f32>>>();
+        let two = architect
+            .weighted_acyclic(2, 2)
+            .into_iter()
+            .collect::<Vec<Node<f32>>>();
+
+        for (node_one, node_two) in one.iter().zip(two.iter()) {
+            assert_eq!(node_one.innovation, node_two.innovation);
+        }
+
+        let mut grown_one = one.clone();
+        grown_one.push(factory.new_node(one.len(), NodeType::Aggregate));
+        let mut graph_one = Graph::from_nodes(grown_one);
+        graph_one.attach(0, one.len()).attach(one.len(), 4);
+
+        let mut grown_two = two.clone();
+        grown_two.push(factory.new_node(two.len(), NodeType::Aggregate));
+        let mut graph_two = Graph::from_nodes(grown_two);
+        graph_two.attach(0, two.len()).attach(two.len(), 4);
+
+        let mut nodes_one = graph_one.into_iter().collect::<Vec<Node<f32>>>();
+        let mut nodes_two = graph_two.into_iter().collect::<Vec<Node<f32>>>();
+
+        let mut innovations = InnovationRegistry::new();
+        innovations.assign_nodes(&mut nodes_one, one.len());
+        innovations.assign_edges(&nodes_one);
+        innovations.assign_nodes(&mut nodes_two, two.len());
+        innovations.assign_edges(&nodes_two);
+        let registered = innovations.len();
+
+        assert_eq!(
+            nodes_one[one.len()].innovation,
+            nodes_two[two.len()].innovation
+        );
+        assert!(one
+            .iter()
+            .all(|node| node.innovation != nodes_one[one.len()].innovation));
+
+        assert_eq!(
+            compatibility_distance(&one, &one, &innovations, 1.0, 1.0, 0.4),
+            0.0
+        );
+        assert!(compatibility_distance(&one, &nodes_one, &innovations, 1.0, 1.0, 0.0) > 0.0);
+        assert_eq!(
+            compatibility_distance(&nodes_one, &nodes_two, &innovations, 1.0, 1.0, 0.0),
+            0.0
+        );
+
+        let mut unseen = nodes_one.clone();
+        unseen.push(factory.new_node(unseen.len(), NodeType::Aggregate));
+        let mut unseen = Graph::from_nodes(unseen);
+        unseen.attach(1, nodes_one.len()).attach(nodes_one.len(), 4);
+        let unseen = unseen.into_iter().collect::<Vec<Node<f32>>>();
+
+        assert!(compatibility_distance(&nodes_one, &unseen, &innovations, 1.0, 1.0, 0.0) > 0.0);
+        assert_eq!(innovations.len(), registered);
+    }
+
+    #[test]
+    fn neat_crossover_aligns_genes_by_innovation() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let mutator = GraphMutator::<f32>::new(factory.clone(), vec![]);
+        let crossover = NeatCrossover::<f32>::new(1.0);
+        let mut innovations = InnovationRegistry::new();
+
+        let base = Architect::<Graph<f32>, f32>::new(&factory)
+            .weighted_acyclic(2, 2)
+            .into_iter()
+            .collect::<Vec<Node<f32>>>();
+
+        for _ in 0..20 {
+            let mut fitter = base.clone();
+            if let Some(mut grown) = mutator.insert_forward_node(&fitter, &NodeType::Aggregate) {
+                innovations.assign_nodes(&mut grown, fitter.len());
+                fitter = grown;
+            }
+
+            let child = crossover.cross_nodes(&fitter, &base, false);
+
+            assert_eq!(child.len(), fitter.len());
+            assert!(Graph::from_nodes(child.clone()).is_valid());
+            for (child_node, fitter_node) in child.iter().zip(fitter.iter()) {
+                assert_eq!(child_node.innovation, fitter_node.innovation);
+                assert_eq!(child_node.incoming, fitter_node.incoming);
+            }
+        }
+
+        let of_type = |node_type: NodeType| {
+            base.iter()
+                .filter(|node| node.node_type == node_type)
+                .map(|node| node.index)
+                .collect::<Vec<usize>>()
+        };
+        let (inputs, outputs) = (of_type(NodeType::Input), of_type(NodeType::Output));
+
+        let mut one = Graph::from_nodes(base.clone());
+        let mut two = Graph::from_nodes(base.clone());
+        one.attach(inputs[0], outputs[0]);
+        two.attach(inputs[1], outputs[1]);
+
+        let child =
+            Graph::from_nodes(crossover.cross_nodes(one.get_nodes(), two.get_nodes(), true));
+        assert!(child.is_valid());
+        assert!(child.nodes[outputs[0]].incoming.contains(&inputs[0]));
+        assert!(child.nodes[outputs[1]].incoming.contains(&inputs[1]));
+
+        let child =
+            Graph::from_nodes(crossover.cross_nodes(one.get_nodes(), two.get_nodes(), false));
+        assert!(!child.nodes[outputs[1]].incoming.contains(&inputs[1]));
+    }
+
+    #[test]
+    fn graph_distance_separates_topologies() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let codex =
+            GraphCodex::from_factory(&factory).set_nodes(|arc, _| arc.weighted_acyclic(2, 2));
+        let mutator = GraphMutator::<f32>::new(factory.clone(), vec![]);
+
+        let genotype = codex.encode();
+        let mut innovations = codex.innovations.write().unwrap();
+        assert_eq!(
+            graph_distance(
+                &genotype,
+                &genotype,
+                &innovations,
+                &Compatibility::default()
+            ),
+            0.0
+        );
+
+        let nodes = genotype.get_chromosome(0).genes.clone();
+        if let Some(mut grown) = mutator.insert_forward_node(&nodes, &NodeType::Aggregate) {
+            innovations.assign_nodes(&mut grown, nodes.len());
+            innovations.assign_edges(&grown);
+            let grown = Genotype::from_chromosomes(vec![Chromosome::from_genes(grown)]);
+
+            let weights_only = Compatibility::new(0.0, 0.0, 1.0);
+
+            assert!(
+                graph_distance(&genotype, &grown, &innovations, &Compatibility::default()) > 0.0
+            );
+            assert_eq!(
+                graph_distance(&genotype, &grown, &innovations, &weights_only),
+                0.0
+            );
+        }
+        drop(innovations);
+
+        let mutator = GraphMutator::<f32>::new(
+            factory.clone(),
+            vec![NodeMutate::Forward(NodeType::Aggregate, 1.0)],
+        )
+        .with_innovations(codex.innovations());
+        let mut population =
+            Population::from_fn(20, || Phenotype::from_genotype(codex.encode(), 0));
+        mutator.alter(&mut population, &Optimize::Minimize, 1);
+
+        let innovations = codex.innovations.read().unwrap();
+        for phenotype in population.iter() {
+            let nodes = &phenotype.genotype().get_chromosome(0).genes;
+            for node in nodes.iter() {
+                for target in node.outgoing.iter() {
+                    assert!(innovations
+                        .edge_innovation(nodes, node.index, *target)
+                        .is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn simplify_folds_constants_and_prunes_dead_nodes() {
+        let mut graph = Graph::from_nodes(vec![
+            Node::new(0, NodeType::Input, op::var(0)),
+            Node::new(1, NodeType::Input, op::var(1)),
+            Node::new(2, NodeType::Gate, op::value(2.0)).set_arity(0),
+            Node::new(3, NodeType::Gate, op::value(3.0)).set_arity(0),
+            Node::new(4, NodeType::Gate, op::add()).set_arity(2),
+            Node::new(5, NodeType::Gate, op::sub()).set_arity(2),
+            Node::new(6, NodeType::Aggregate, op::linear()),
+            Node::new(7, NodeType::Aggregate, op::sigmoid()),
+            Node::new(8, NodeType::Output, op::linear()),
+        ]);
+        graph
+            .attach(2, 4)
+            .attach(3, 4)
+            .attach(0, 5)
+            .attach(4, 5)
+            .attach(5, 6)
+            .attach(6, 8)
+            .attach(1, 7);
+
+        let simplified = graph.simplify();
+
+        assert_eq!(simplified.len(), 5);
+        assert!(simplified
+            .iter()
+            .all(|node| node.node_type != NodeType::Aggregate));
+        for node in simplified.iter() {
+            for incoming in node.incoming.iter() {
+                assert!(simplified.nodes[*incoming].outgoing.contains(&node.index));
+            }
+        }
+
+        let mut reducer = GraphReducer::new(&graph);
+        let mut simple_reducer = GraphReducer::new(&simplified);
+        for input in [[0.5, 1.0], [-2.0, 3.0], [4.0, 0.0]] {
+            assert_eq!(reducer.reduce(&input), simple_reducer.reduce(&input));
+        }
+    }
+
+    #[test]
+    fn simplify_keeps_clamping_identities() {
+        let mut graph = Graph::from_nodes(vec![
+            Node::new(0, NodeType::Input, op::var(0)),
+            Node::new(1, NodeType::Aggregate, op::linear()),
+            Node::new(2, NodeType::Aggregate, op::sum()),
+            Node::new(3, NodeType::Output, op::linear()),
+        ]);
+        graph.attach(0, 1).attach(1, 2).attach(2, 3);
+
+        let simplified = graph.simplify();
+
+        assert_eq!(simplified.len(), 3);
+
+        let mut reducer = GraphReducer::new(&graph);
+        let mut simple_reducer = GraphReducer::new(&simplified);
+        for input in [[1e7], [-1e7], [0.25]] {
+            assert_eq!(reducer.reduce(&input), simple_reducer.reduce(&input));
+        }
+    }
+
+    #[test]
+    fn simplify_preserves_evolved_graph_outputs() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let mutator = GraphMutator::<f32>::new(factory.clone(), vec![]);
+
+        for _ in 0..20 {
+            let mut nodes = Architect::<Graph<f32>, f32>::new(&factory)
+                .weighted_acyclic(2, 1)
+                .into_iter()
+                .collect::<Vec<Node<f32>>>();
+
+            for node_type in [NodeType::Aggregate, NodeType::Gate, NodeType::Weight] {
+                if let Some(grown) = mutator.insert_forward_node(&nodes, &node_type) {
+                    nodes = grown;
+                }
+            }
+
+            let mut graph = Graph::from_nodes(nodes);
+            let dangling = graph.len();
+            graph.add(vec![factory.new_node(dangling, NodeType::Aggregate)]);
+            graph.attach(0, dangling);
+
+            let simplified = graph.simplify();
+            assert!(simplified.len() < graph.len());
+
+            let mut reducer = GraphReducer::new(&graph);
+            let mut simple_reducer = GraphReducer::new(&simplified);
+            for input in [[0.5, 1.0], [-2.0, 3.0], [0.1, -0.7]] {
+                assert_eq!(reducer.reduce(&input), simple_reducer.reduce(&input));
+            }
+        }
+    }
+
+    #[test]
+    fn graph_exports_to_dot_and_mermaid() {
+        let mut graph = Graph::from_nodes(vec![
+            Node::new(0, NodeType::Input, op::var(0)),
+            Node::new(1, NodeType::Aggregate, op::linear()),
+            Node::new(2, NodeType::Gate, op::value(2.0)).set_arity(0),
+            Node::new(3, NodeType::Output, op::linear()),
+        ]);
+        graph.attach(0, 1).attach(1, 1).attach(1, 3).attach(2, 3);
+        graph.nodes[2].enabled = false;
+        let graph = graph.set_cycles(vec![]);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph G {"));
+        assert!(dot.contains("n0 [label=\"0 Input\\nx0\", shape=box];"));
+        assert!(dot.contains("n1 -> n1 [style=dashed"));
+        assert!(dot.contains("n1 -> n3;"));
+        assert!(dot.contains("fillcolor=lightgray"));
+
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.starts_with("flowchart LR"));
+        assert!(mermaid.contains("n3((\"3 Output<br/>linear\"))"));
+        assert!(mermaid.contains("n1 -.-> n1"));
+        assert!(mermaid.contains("n0 --> n1"));
+        assert!(mermaid.contains("class n2 disabled;"));
+        assert!(mermaid.contains("class n1 recurrent;"));
+    }
+
+    #[test]
+    fn graph_renders_symbolic_expressions() {
+        let mut graph = Graph::from_nodes(vec![
+            Node::new(0, NodeType::Input, op::var(0)),
+            Node::new(1, NodeType::Input, op::var(1)),
+            Node::new(2, NodeType::Gate, op::add()).set_arity(2),
+            Node::new(3, NodeType::Gate, op::sin()).set_arity(1),
+            Node::new(4, NodeType::Gate, op::mul()).set_arity(2),
+            Node::new(5, NodeType::Gate, op::value(2.0)).set_arity(0),
+            Node::new(6, NodeType::Output, op::linear()),
+        ]);
+        graph
+            .attach(0, 2)
+            .attach(1, 2)
+            .attach(0, 3)
+            .attach(2, 4)
+            .attach(5, 4)
+            .attach(3, 6)
+            .attach(4, 6);
+
+        assert_eq!(
+            graph.to_expressions(&["x", "y"]),
+            vec!["(sin(x) + ((x + y) * 2.0))"]
+        );
+        assert_eq!(
+            graph.to_latex(&["x", "y"]),
+            vec!["(\\sin\\left(x\\right) + (x + y) \\cdot 2.0)"]
+        );
+
+        let source = graph.to_rust(&["x", "y"], "model");
+        assert!(source.starts_with("pub fn model(x: f32, y: f32) -> [f32; 1] {"));
+        assert!(source.contains("(x.sin() + ((x + y) * 2.0_f32))"));
+
+        let mut recurrent = Graph::from_nodes(vec![
+            Node::new(0, NodeType::Input, op::var(0)),
+            Node::new(1, NodeType::Aggregate, op::tanh()),
+            Node::new(2, NodeType::Output, op::linear()),
+        ]);
+        recurrent.attach(0, 1).attach(1, 1).attach(1, 2);
+
+        assert_eq!(recurrent.to_expressions(&[]), vec!["tanh(x0 + n1[t-1])"]);
+        assert!(recurrent
+            .to_rust(&[], "step")
+            .starts_with("pub fn step(x0: f32, prev: &[f32]) -> [f32; 1] {"));
+    }
+
+    #[test]
+    fn compiled_tape_matches_reducer() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let mutator = GraphMutator::<f32>::new(factory.clone(), vec![]);
+        let samples = SampleSet::from_vecs(
+            vec![vec![0.5, 1.0], vec![-2.0, 3.0], vec![0.1, -0.7]],
+            vec![vec![0.0], vec![0.0], vec![0.0]],
+        );
+
+        for outputs in [1, 3] {
+            for _ in 0..20 {
+                let mut nodes = Architect::<Graph<f32>, f32>::new(&factory)
+                    .weighted_acyclic(2, outputs)
+                    .into_iter()
+                    .collect::<Vec<Node<f32>>>();
+
+                for node_type in [NodeType::Aggregate, NodeType::Gate, NodeType::Weight] {
+                    if let Some(grown) = mutator.insert_forward_node(&nodes, &node_type) {
+                        nodes = grown;
+                    }
+                }
+
+                let graph = Graph::from_nodes(nodes);
+                let mut reducer = GraphReducer::new(&graph);
+                let mut tape = graph.compile();
+
+                let expected = samples
+                    .get_samples()
+                    .iter()
+                    .map(|sample| reducer.reduce(&sample.1))
+                    .collect::<Vec<Vec<f32>>>();
+
+                assert_eq!(tape.output_size(), outputs);
+                assert_eq!(tape.eval_batch(&samples), expected);
+            }
+        }
+
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).weighted_cyclic(2, 1, 2);
+        let mut reducer = GraphReducer::new(&graph);
+        let mut tape = graph.compile();
+
+        for sample in samples.get_samples().iter().cycle().take(9) {
+            assert_eq!(tape.eval(&sample.1), reducer.reduce(&sample.1));
+        }
+    }
+
+    #[test]
+    fn recurrent_execution_resets_and_restores_state() {
+        let factory = NodeFactory::<f32>::regression(1);
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).weighted_cyclic(1, 1, 2);
+        let sequence = vec![vec![1.0], vec![0.0], vec![0.5], vec![-1.0]];
+
+        let mut reducer = GraphReducer::new(&graph);
+        let first = reducer.run_sequence(&sequence);
+        let second = reducer.run_sequence(&sequence);
+        assert_eq!(first, second);
+
+        reducer.reset();
+        reducer.step(&sequence[0]);
+        let state = reducer.snapshot();
+        let expected = reducer.step(&sequence[1]);
+        reducer.step(&sequence[2]);
+        reducer.restore(&state);
+        assert!(reducer.snapshot() == state);
+        assert_eq!(reducer.step(&sequence[1]), expected);
+
+        let mut tape = graph.compile();
+        assert_eq!(tape.run_sequence(&sequence), first);
+
+        let mut settled = GraphReducer::new(&graph).with_settling(3);
+        let mut settled_tape = graph.compile().with_settling(3);
+        assert_eq!(
+            settled.run_sequence(&sequence),
+            settled_tape.run_sequence(&sequence)
+        );
+
+        let regression = Regression::new(
+            SampleSet::from_vecs(sequence.clone(), sequence.clone()),
+            ErrorFunction::MSE,
+        )
+        .with_sequence_length(2);
+        assert_eq!(regression.sequences().len(), 2);
+
+        let error = regression.sequence_error(|inputs| {
+            let mut reducer = GraphReducer::new(&graph);
+            reducer.run_sequence(inputs)
+        });
+        let expected = regression.sequence_error(|inputs| tape.run_sequence(inputs));
+        assert_eq!(error, expected);
+    }
+
+    #[test]
+    fn json_writes_non_finite_values_as_strings() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let mut graph = Architect::<Graph<f32>, f32>::new(&factory).weighted_acyclic(2, 2);
+
+        let values = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY];
+        let mut written = 0;
+        for node in graph.nodes.iter_mut() {
+            if let Ops::MutableConst(_, _, weight, _, _) = &mut node.value {
+                *weight = values[written % values.len()];
+                written += 1;
+            }
+        }
+        assert!(written >= values.len());
+
+        let json = graph.to_json();
+        for value in ["\"NaN\"", "\"inf\"", "\"-inf\""] {
+            assert!(json.contains(value));
+        }
+
+        let loaded = Graph::<f32>::from_json(&json, &OpRegistry::standard()).unwrap();
+        assert_eq!(loaded.to_json(), json);
+    }
+
+    #[test]
+    fn json_rejects_malformed_numbers() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).weighted_acyclic(2, 1);
+        let json = graph.to_json();
+        assert!(Graph::<f32>::from_json(&json, &OpRegistry::standard()).is_ok());
+
+        for number in [
+            "01", "1.", ".5", "+1", "1e", "-", "0x1", "1abc", "NaN", "--1",
+        ] {
+            let broken = json.replacen("\"index\": 0", &format!("\"index\": {}", number), 1);
+            assert!(Graph::<f32>::from_json(&broken, &OpRegistry::standard()).is_err());
+        }
+    }
+
+    #[test]
+    fn json_round_trip_rebuilds_executable_graph() {
+        let double = || {
+            Ops::Fn(
+                "double",
+                1,
+                std::sync::Arc::new(|inputs: &[f32]| inputs.iter().sum::<f32>() * 2.0),
+            )
+        };
+        let factory = NodeFactory::<f32>::regression(2);
+        let mut graph = Architect::<Graph<f32>, f32>::new(&factory).weighted_cyclic(2, 1, 2);
+
+        let output = graph
+            .iter()
+            .position(|node| node.node_type == NodeType::Output)
+            .unwrap();
+        graph.nodes[output].value = double();
+
+        let json = graph.to_json();
+        assert!(Graph::<f32>::from_json(&json, &OpRegistry::standard()).is_err());
+
+        let registry = OpRegistry::standard().register("double", double);
+        let loaded = Graph::<f32>::from_json(&json, &registry).unwrap();
+
+        assert_eq!(loaded.len(), graph.len());
+        assert_eq!(loaded.to_json(), json);
+
+        let mut expected = GraphReducer::new(&graph);
+        let mut actual = GraphReducer::new(&loaded);
+        for inputs in [vec![0.5, 1.0], vec![-2.0, 3.0], vec![0.1, -0.7]] {
+            assert_eq!(actual.reduce(&inputs), expected.reduce(&inputs));
+        }
+    }
+
+    #[test]
+    fn layered_architect_builds_biased_network_with_skips() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let layers = Layers::new(&[2, 3, 2, 1])
+            .hidden_activation("relu")
+            .activation(3, "sigmoid")
+            .bias(1.0)
+            .skip(0, 2);
+
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).layered_with(&layers);
+
+        let count = |node_type: NodeType| {
+            graph
+                .iter()
+                .filter(|node| node.node_type == node_type)
+                .count()
+        };
+        assert_eq!(count(NodeType::Input), 2);
+        assert_eq!(count(NodeType::Aggregate), 5);
+        assert_eq!(count(NodeType::Output), 1);
+        assert_eq!(count(NodeType::Gate), 1);
+        assert_eq!(count(NodeType::Weight), 6 + 6 + 2 + 4 + 6);
+        assert!(graph.is_valid());
+        assert!(graph.iter().all(|node| !node.is_recurrent()));
+
+        let incoming = graph
+            .iter()
+            .filter(|node| node.node_type == NodeType::Aggregate)
+            .map(|node| (node.value.name().to_string(), node.incoming.len()))
+            .collect::<Vec<(String, usize)>>();
+        assert_eq!(
+            incoming,
+            vec![
+                (String::from("relu"), 3),
+                (String::from("relu"), 3),
+                (String::from("relu"), 3),
+                (String::from("relu"), 6),
+                (String::from("relu"), 6),
+            ]
+        );
+
+        let output = graph
+            .iter()
+            .find(|node| node.node_type == NodeType::Output)
+            .unwrap();
+        assert_eq!(output.value.name(), "sigmoid");
+        assert_eq!(output.incoming.len(), 3);
+
+        let mut reducer = GraphReducer::new(&graph);
+        let mut tape = graph.compile();
+        for inputs in [vec![0.5, 1.0], vec![-2.0, 3.0]] {
+            let outputs = reducer.reduce(&inputs);
+            assert_eq!(outputs.len(), 1);
+            assert_eq!(tape.eval(&inputs), outputs);
+        }
+
+        let plain = Architect::<Graph<f32>, f32>::new(&factory).layered(&[3, 4, 2]);
+        assert_eq!(plain.len(), 3 + 4 + 2 + 12 + 8);
+        assert!(plain.is_valid());
+    }
+
+    #[test]
+    fn op_derivatives_match_finite_differences() {
+        let registry = OpRegistry::standard();
+        let inputs = [0.7_f32, -0.3_f32];
+
+        for name in registry.constructors.keys() {
+            let value = registry.create(name).unwrap();
+            let args = &inputs[..(value.arity() as usize).clamp(1, 2)];
+            let output = value.apply(args);
+
+            for (i, analytic) in derivative(&value, args, output).into_iter().enumerate() {
+                let mut upper = args.to_vec();
+                let mut lower = args.to_vec();
+                upper[i] += 1e-3;
+                lower[i] -= 1e-3;
+                let numeric = (value.apply(&upper) - value.apply(&lower)) / 2e-3;
+
+                assert!(
+                    (analytic - numeric).abs() < 1e-2,
+                    "{} d{}: {} != {}",
+                    name,
+                    i,
+                    analytic,
+                    numeric
+                );
+            }
+        }
+
+        let weight = Ops::MutableConst(
+            "w",
+            1,
+            0.25,
+            std::sync::Arc::new(|| 0.0),
+            std::sync::Arc::new(|inputs: &[f32], weight: &f32| inputs[0] * weight),
+        );
+        assert_eq!(derivative(&weight, &[2.0], 0.5), vec![0.25]);
+        assert_eq!(const_derivative(&weight, &[2.0]), 2.0);
+    }
+
+    #[test]
+    fn graph_trainer_backpropagates_through_time_and_reduces_loss() {
+        let factory = NodeFactory::<f32>::regression(1).aggregates(vec![
+            op::tanh(),
+            op::sigmoid(),
+            op::linear(),
+        ]);
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).weighted_cyclic(1, 1, 2);
+        let regression = Regression::new(
+            SampleSet::from_vecs(
+                vec![
+                    vec![0.5],
+                    vec![-0.2],
+                    vec![0.9],
+                    vec![0.1],
+                    vec![-0.6],
+                    vec![0.3],
+                ],
+                vec![
+                    vec![0.1],
+                    vec![0.4],
+                    vec![-0.3],
+                    vec![0.2],
+                    vec![0.5],
+                    vec![0.0],
+                ],
+            ),
+            ErrorFunction::MSE,
+        )
+        .with_sequence_length(3);
+
+        let loss_of = |graph: &Graph<f32>| {
+            let mut tape = graph.compile();
+            regression.sequence_error(|sequence| tape.run_sequence(sequence))
+        };
+
+        let trainer = GraphTrainer::new(Optimizer::sgd(0.1));
+        let (loss, gradients) = trainer.gradients(&graph, &regression);
+        assert!((loss - loss_of(&graph)).abs() < 1e-6);
+
+        for node in graph.iter() {
+            if let Ops::MutableConst(_, _, value, _, _) = node.value {
+                let mut upper = graph.clone();
+                let mut lower = graph.clone();
+                if let Ops::MutableConst(_, _, ref mut weight, _, _) = upper.nodes[node.index].value
+                {
+                    *weight = value + 1e-2;
+                }
+                if let Ops::MutableConst(_, _, ref mut weight, _, _) = lower.nodes[node.index].value
+                {
+                    *weight = value - 1e-2;
+                }
+
+                let numeric = (loss_of(&upper) - loss_of(&lower)) / 2e-2;
+                assert!(
+                    (gradients[node.index] - numeric).abs() < 1e-2 * numeric.abs().max(1.0),
+                    "node {}: {} != {}",
+                    node.index,
+                    gradients[node.index],
+                    numeric
+                );
+            }
+        }
+
+        let linear = Architect::<Graph<f32>, f32>::new(&factory).weighted_acyclic(1, 1);
+        let line = Regression::new(
+            SampleSet::from_vecs(
+                vec![vec![-1.0], vec![-0.5], vec![0.5], vec![1.0]],
+                vec![vec![1.5], vec![0.75], vec![-0.75], vec![-1.5]],
+            ),
+            ErrorFunction::MSE,
+        );
+
+        let mut genotype =
+            Genotype::from_chromosomes(vec![Chromosome::from_genes(linear.nodes.clone())]);
+        let before = loss_of_samples(&linear, &line);
+        let after = GraphTrainer::new(Optimizer::adam(0.1))
+            .steps(200)
+            .train_genotype(&mut genotype, &line);
+
+        assert!(after < before);
+        assert!(after < 1e-4);
+        assert!(
+            (loss_of_samples(
+                &Graph::from_nodes(genotype.chromosomes[0].genes.clone()),
+                &line
+            ) - after)
+                .abs()
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn trainer_fits_each_output_to_its_own_target() {
+        let factory = NodeFactory::<f32>::regression(1);
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).weighted_acyclic(1, 3);
+        let inputs = vec![vec![-1.0], vec![-0.5], vec![0.5], vec![1.0]];
+        let regression = Regression::new(
+            SampleSet::from_vecs(
+                inputs.clone(),
+                inputs
+                    .iter()
+                    .map(|input| vec![2.0 * input[0], -input[0], 0.5 * input[0]])
+                    .collect(),
+            ),
+            ErrorFunction::MSE,
+        );
+
+        let mut trained = graph.clone();
+        let loss = GraphTrainer::new(Optimizer::adam(0.1))
+            .steps(300)
+            .train(&mut trained, &regression);
+
+        let mut reducer = GraphReducer::new(&trained);
+        assert!(loss < 1e-4);
+        assert!(regression.error(|input| reducer.reduce(input)) < 1e-4);
+    }
+
+    #[test]
+    fn engine_applies_lamarckian_training() {
+        let factory = NodeFactory::<f32>::regression(1);
+        let nodes = Architect::<Graph<f32>, f32>::new(&factory)
+            .weighted_acyclic(1, 1)
+            .into_iter()
+            .collect::<Vec<Node<f32>>>();
+        let codex = GraphCodex::from_nodes(nodes, &factory);
+        let regression = std::rc::Rc::new(Regression::new(
+            SampleSet::from_vecs(
+                vec![vec![-1.0], vec![-0.5], vec![0.5], vec![1.0]],
+                vec![vec![1.5], vec![0.75], vec![-0.75], vec![-1.5]],
+            ),
+            ErrorFunction::MSE,
+        ));
+
+        let trained = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = trained.clone();
+        let lamarck_regression = regression.clone();
+        let engine = GeneticEngine::from_codex(&codex)
+            .population_size(10)
+            .minimizing()
+            .alterer(vec![OpMutator::alterer(factory.clone(), 0.1, 0.05)])
+            .lamarck_fn(move |genotype: &mut Genotype<Node<f32>, Ops<f32>>| {
+                counter.set(counter.get() + 1);
+                GraphTrainer::new(Optimizer::adam(0.1))
+                    .steps(50)
+                    .train_genotype(genotype, &lamarck_regression);
+            })
+            .fitness_fn(move |graph: Graph<f32>| {
+                let mut reducer = GraphReducer::new(&graph);
+                Score::from_f32(regression.error(|input| reducer.reduce(input)))
+            })
+            .build();
+
+        let result = engine.run(|output| output.index == 5);
+
+        assert!(trained.get() > 0);
+        assert!(result.score().as_float() < 1e-2);
+    }
+
+    fn loss_of_samples(graph: &Graph<f32>, regression: &Regression<f32>) -> f32 {
+        let mut tape = graph.compile();
+        regression.error(|input| tape.eval(input))
+    }
 }
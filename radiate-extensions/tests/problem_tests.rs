@@ -0,0 +1,519 @@
+#[cfg(test)]
+mod tests {
+
+    use radiate_extensions::*;
+
+    fn samples() -> SampleSet<f32> {
+        SampleSet::from_vecs(
+            vec![vec![0.0], vec![1.0], vec![2.0], vec![3.0]],
+            vec![
+                vec![1.0, 0.0],
+                vec![0.0, 1.0],
+                vec![1.0, 1.0],
+                vec![0.0, 0.0],
+            ],
+        )
+    }
+
+    fn outputs() -> Vec<Vec<f32>> {
+        vec![
+            vec![0.8, 0.1],
+            vec![0.3, 0.6],
+            vec![0.9, 0.7],
+            vec![0.2, 0.4],
+        ]
+    }
+
+    fn calculate(loss: &ErrorFunction<f32>) -> f32 {
+        let outputs = outputs();
+        let mut index = 0;
+        loss.calculate(&samples(), &mut |_| {
+            index += 1;
+            outputs[index - 1].clone()
+        })
+    }
+
+    #[test]
+    fn error_functions_compute_expected_losses() {
+        let close = |actual: f32, expected: f32| (actual - expected).abs() < 1e-4;
+
+        assert!(close(calculate(&ErrorFunction::MSE), 0.6 / 4.0));
+        assert!(close(calculate(&ErrorFunction::MAE), 2.0 / 4.0));
+        assert!(close(
+            calculate(&ErrorFunction::RMSE),
+            (0.6_f32 / 4.0).sqrt()
+        ));
+        assert!(close(calculate(&ErrorFunction::Diff), 2.0));
+        assert!(close(calculate(&ErrorFunction::Huber(0.25)), 0.275 / 4.0));
+        assert!(close(
+            calculate(&ErrorFunction::MSE.weighted(vec![2.0, 0.0])),
+            2.0 * (0.04 + 0.09 + 0.01 + 0.04) / 4.0
+        ));
+        assert!(close(
+            calculate(&ErrorFunction::custom(
+                |expected: &[f32], actual: &[f32]| { (expected[0] - actual[0]).abs() }
+            )),
+            0.8 / 4.0
+        ));
+
+        let cross_entropy = -(0.8_f32.ln() + 0.6_f32.ln() + 0.9_f32.ln() + 0.7_f32.ln()) / 4.0;
+        assert!(close(
+            calculate(&ErrorFunction::CrossEntropy),
+            cross_entropy
+        ));
+        assert!(close(
+            calculate(&ErrorFunction::CategoricalCrossEntropy(1e-7)),
+            cross_entropy
+        ));
+        assert!(calculate(&ErrorFunction::BinaryCrossEntropy(1e-7)) > cross_entropy);
+        assert!(calculate(&ErrorFunction::CrossEntropy).is_finite());
+
+        let perfect = SampleSet::from_vecs(vec![vec![0.0], vec![1.0]], vec![vec![0.0], vec![1.0]]);
+        let mut identity = |input: &Vec<f32>| input.clone();
+        assert!(close(
+            ErrorFunction::UnexplainedVariance.calculate(&perfect, &mut identity),
+            0.0
+        ));
+        let mut mean = |_: &Vec<f32>| vec![0.5];
+        assert!(close(
+            ErrorFunction::UnexplainedVariance.calculate(&perfect, &mut mean),
+            1.0
+        ));
+        assert!(close(
+            ErrorFunction::MAPE.calculate(&perfect, &mut identity),
+            0.0
+        ));
+        assert!(close(
+            ErrorFunction::CategoricalCrossEntropy(1e-3).calculate(&perfect, &mut |_| vec![0.0]),
+            -(1e-3_f32).ln() / 2.0
+        ));
+
+        let signs = SampleSet::from_vecs(vec![vec![0.0], vec![0.0]], vec![vec![1.0], vec![-1.0]]);
+        assert!(close(
+            ErrorFunction::Hinge.calculate(&signs, &mut |_| vec![0.5]),
+            2.0 / 2.0
+        ));
+
+        let regression = Regression::new(samples(), ErrorFunction::MAE);
+        assert!(close(regression.error(|_| vec![0.0, 0.0]), 4.0 / 4.0));
+    }
+
+    #[test]
+    fn error_function_gradients_match_finite_differences() {
+        let losses = vec![
+            ErrorFunction::MSE,
+            ErrorFunction::MAE,
+            ErrorFunction::RMSE,
+            ErrorFunction::Huber(0.25),
+            ErrorFunction::CrossEntropy,
+            ErrorFunction::BinaryCrossEntropy(1e-7),
+            ErrorFunction::CategoricalCrossEntropy(1e-7),
+            ErrorFunction::Hinge,
+            ErrorFunction::UnexplainedVariance,
+            ErrorFunction::MAPE,
+            ErrorFunction::Diff,
+            ErrorFunction::custom(|expected: &[f32], actual: &[f32]| {
+                expected
+                    .iter()
+                    .zip(actual.iter())
+                    .map(|(e, a)| (e - a).powi(4))
+                    .sum::<f32>()
+            }),
+            ErrorFunction::RMSE.weighted(vec![0.5, 3.0]),
+        ];
+
+        let samples = SampleSet::from_vecs(
+            vec![vec![0.0], vec![1.0], vec![2.0], vec![3.0]],
+            vec![
+                vec![1.0, 0.5],
+                vec![0.2, 1.0],
+                vec![1.0, 0.7],
+                vec![0.4, 0.9],
+            ],
+        );
+        let outputs = outputs();
+
+        for loss in losses.iter() {
+            let gradient = loss.gradient(&samples, &outputs);
+
+            for sample in 0..outputs.len() {
+                for index in 0..outputs[sample].len() {
+                    let mut upper = outputs.clone();
+                    let mut lower = outputs.clone();
+                    upper[sample][index] += 1e-3;
+                    lower[sample][index] -= 1e-3;
+
+                    let numeric =
+                        (loss.loss(&samples, &upper) - loss.loss(&samples, &lower)) / 2e-3;
+                    let analytic = gradient[sample][index];
+                    assert!(
+                        (analytic - numeric).abs() < 1e-2 * numeric.abs().max(1.0),
+                        "{} != {}",
+                        analytic,
+                        numeric
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sample_set_loads_csv_and_splits() {
+        let text = "x,label,y\n1.0,a,2.0\n2.0,b,4.0\n\n3.0,c,6.0\n4.0,d,8.0\n";
+        let format = CsvFormat::csv().header().inputs(["x"]).targets([2]);
+        let samples = SampleSet::<f32>::from_csv(text, &format).unwrap();
+
+        assert_eq!(samples.len(), 4);
+        assert_eq!(samples.get_samples()[2].1, vec![3.0]);
+        assert_eq!(samples.get_samples()[2].2, vec![6.0]);
+
+        let tsv = SampleSet::<f32>::from_csv("1\t2\t3\n4\t5\t6", &CsvFormat::tsv()).unwrap();
+        assert_eq!(tsv.get_samples()[1].1, vec![4.0, 5.0]);
+        assert_eq!(tsv.get_samples()[1].2, vec![6.0]);
+
+        assert!(SampleSet::<f32>::from_csv(text, &CsvFormat::csv().header()).is_err());
+        assert!(SampleSet::<f32>::from_csv("1,2\n3", &CsvFormat::csv()).is_err());
+        assert!(SampleSet::<f32>::from_csv(text, &format.clone().targets(["z"])).is_err());
+
+        let (train, validation, test) = samples.train_validation_test(0.5, 0.25);
+        assert_eq!((train.len(), validation.len(), test.len()), (2, 1, 1));
+        assert_eq!(test.get_samples()[0].0, 0);
+        assert_eq!(test.get_samples()[0].1, samples.get_samples()[3].1);
+
+        let folds = samples.k_fold(2).collect::<Vec<_>>();
+        assert_eq!(folds.len(), 2);
+        for (train, validation) in folds.iter() {
+            assert_eq!((train.len(), validation.len()), (2, 2));
+        }
+        assert_eq!(folds[1].1.get_samples()[0].0, 0);
+        assert_eq!(folds[1].1.get_samples()[0].1, samples.get_samples()[2].1);
+
+        let mut shuffled = samples.clone();
+        shuffled.shuffle();
+        let mut indices = shuffled
+            .get_samples()
+            .iter()
+            .map(|sample| sample.0)
+            .collect::<Vec<usize>>();
+        indices.sort();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+
+        assert_eq!(samples.batch(3).len(), 3);
+        assert_eq!(samples.batch(10).len(), 4);
+        let batches = samples.batches(3);
+        assert_eq!(batches.iter().map(|batch| batch.len()).sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn normalizer_scales_and_inverts_samples() {
+        let close = |actual: f32, expected: f32| (actual - expected).abs() < 1e-4;
+
+        for scaling in [Scaling::MinMax, Scaling::ZScore] {
+            let original = SampleSet::from_vecs(
+                vec![vec![1.0, 5.0], vec![3.0, 5.0], vec![5.0, 5.0]],
+                vec![vec![10.0], vec![20.0], vec![60.0]],
+            );
+            let mut samples = original.clone();
+            let normalizer = samples.normalize(scaling);
+
+            let column = samples
+                .get_samples()
+                .iter()
+                .map(|sample| sample.1[0])
+                .collect::<Vec<f32>>();
+            match scaling {
+                Scaling::MinMax => assert_eq!(column, vec![0.0, 0.5, 1.0]),
+                Scaling::ZScore => assert!(close(column.iter().sum::<f32>(), 0.0)),
+            }
+            assert!(samples
+                .get_samples()
+                .iter()
+                .all(|sample| sample.1[1].is_finite()));
+
+            for (scaled, sample) in samples.get_samples().iter().zip(original.get_samples()) {
+                assert!(close(normalizer.inverse_output(&scaled.2)[0], sample.2[0]));
+                assert!(close(normalizer.inverse_input(&scaled.1)[1], sample.1[1]));
+                assert!(close(normalizer.transform_input(&sample.1)[0], scaled.1[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn regression_evaluates_on_mini_batches() {
+        let samples = SampleSet::from_vecs(
+            (0..20).map(|index| vec![index as f32]).collect(),
+            (0..20).map(|index| vec![index as f32]).collect(),
+        );
+        let regression = Regression::new(samples, ErrorFunction::MAE).with_batch_size(5);
+
+        let mut calls = 0;
+        regression.error(|input| {
+            calls += 1;
+            input.clone()
+        });
+        assert_eq!(calls, 5);
+
+        let batch = |regression: &Regression<f32>| {
+            let mut inputs = Vec::new();
+            regression.error(|input| {
+                inputs.push(input[0]);
+                input.clone()
+            });
+            inputs
+        };
+
+        assert!(regression.next_batch(0));
+        assert!(!regression.next_batch(0));
+
+        let current = batch(&regression);
+        assert_eq!(batch(&regression), current);
+
+        let changed = (1..20).any(|generation| {
+            regression.next_batch(generation);
+            batch(&regression) != current
+        });
+        assert!(changed);
+    }
+
+    #[test]
+    fn regression_batches_whole_sequences() {
+        let samples = SampleSet::from_vecs(
+            (0..22).map(|index| vec![index as f32]).collect(),
+            (0..22).map(|index| vec![index as f32]).collect(),
+        );
+        let regression = Regression::new(samples, ErrorFunction::MAE)
+            .with_sequence_length(4)
+            .with_batch_size(8);
+
+        for generation in 0..10 {
+            regression.next_batch(generation);
+
+            let sequences = regression.sequences();
+            assert!(sequences.len() == 2);
+            for sequence in sequences.iter() {
+                assert!(sequence
+                    .windows(2)
+                    .all(|pair| pair[1][0] == pair[0][0] + 1.0));
+            }
+
+            let mut seen = 0;
+            let error = regression.sequence_error(|sequence| {
+                seen += sequence.len();
+                sequence.to_vec()
+            });
+            assert_eq!(error, 0.0);
+            assert_eq!(seen, sequences.iter().map(|sequence| sequence.len()).sum());
+        }
+    }
+
+    #[test]
+    fn sample_subsets_are_renumbered() {
+        let mut samples = SampleSet::from_vecs(
+            (0..10).map(|index| vec![index as f32]).collect(),
+            (0..10).map(|index| vec![index as f32]).collect(),
+        );
+
+        let subset = samples.subset(&[7, 2, 5]);
+        assert_eq!(
+            subset
+                .get_samples()
+                .iter()
+                .map(|sample| (sample.0, sample.1[0]))
+                .collect::<Vec<(usize, f32)>>(),
+            vec![(0, 7.0), (1, 2.0), (2, 5.0)]
+        );
+
+        samples.shuffle();
+        assert!(samples
+            .get_samples()
+            .iter()
+            .enumerate()
+            .all(|(index, sample)| sample.0 == index));
+    }
+
+    #[test]
+    fn classification_reports_metrics() {
+        let close = |actual: f32, expected: f32| (actual - expected).abs() < 1e-4;
+
+        let classification = Classification::<f32>::from_labels(
+            vec![vec![0.0], vec![1.0], vec![2.0], vec![3.0], vec![4.0]],
+            vec![0, 1, 2, 2, 1],
+            3,
+        );
+        let predictions = [
+            vec![0.7, 0.2, 0.1],
+            vec![0.1, 0.8, 0.1],
+            vec![0.2, 0.2, 0.6],
+            vec![0.5, 0.1, 0.4],
+            vec![0.3, 0.4, 0.3],
+        ];
+        let eval = |input: &Vec<f32>| predictions[input[0] as usize].clone();
+
+        let (matrix, log_loss) = classification.evaluate(eval);
+        assert_eq!(classification.classes(), 3);
+        assert_eq!(
+            matrix.counts,
+            vec![vec![1, 0, 0], vec![0, 2, 0], vec![1, 0, 1]]
+        );
+        assert!(close(matrix.accuracy(), 0.8));
+        assert!(close(matrix.precision(0), 0.5));
+        assert!(close(matrix.recall(2), 0.5));
+        assert!(close(matrix.f1(0), 2.0 / 3.0));
+        assert!(close(matrix.f1(1), 1.0));
+        assert!(close(
+            matrix.macro_f1(),
+            (2.0 / 3.0 + 1.0 + 2.0 / 3.0) / 3.0
+        ));
+        assert!(close(
+            log_loss,
+            -(0.7_f32.ln() + 0.8_f32.ln() + 0.6_f32.ln() + 0.4_f32.ln() + 0.4_f32.ln()) / 5.0
+        ));
+        assert!(close(classification.error(eval), 0.2));
+
+        let binary = Classification::new(
+            SampleSet::from_vecs(vec![vec![0.0], vec![1.0]], vec![vec![0.0], vec![1.0]]),
+            Decision::Threshold(0.5),
+        );
+        assert_eq!(binary.classes(), 2);
+        assert!(close(
+            binary.accuracy(|input| vec![input[0] * 0.6 + 0.2]),
+            1.0
+        ));
+        assert!(close(
+            binary.log_loss(|_| vec![0.75]),
+            -(0.25_f32.ln() + 0.75_f32.ln()) / 2.0
+        ));
+    }
+
+    #[test]
+    fn softmax_outputs_produce_class_probabilities() {
+        let factory = NodeFactory::<f32>::regression(3);
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).build(|arc, builder| {
+            builder
+                .all_to_all(&arc.input(3), &arc.softmax_output(3))
+                .build()
+        });
+
+        let inputs = vec![1.0_f32, 2.0, 0.5];
+        let outputs = GraphReducer::new(&graph).reduce(&inputs);
+        let total = inputs.iter().map(|input| input.exp()).sum::<f32>();
+
+        assert!((outputs.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        for (output, input) in outputs.iter().zip(inputs.iter()) {
+            assert!((output - input.exp() / total).abs() < 1e-5);
+        }
+
+        assert!(graph
+            .to_json()
+            .contains("\"kind\": \"Indexed\", \"name\": \"softmax\", \"index\": 2"));
+        let loaded = Graph::<f32>::from_json(&graph.to_json(), &OpRegistry::standard()).unwrap();
+        assert_eq!(GraphReducer::new(&loaded).reduce(&inputs), outputs);
+        assert_eq!(graph.compile().eval(&inputs), outputs);
+
+        let classification = Classification::from_labels(vec![inputs.clone()], vec![1], 3);
+        let (matrix, log_loss) =
+            classification.evaluate(|input| GraphReducer::new(&graph).reduce(input));
+        assert_eq!(matrix.accuracy(), 1.0);
+        assert!((log_loss + outputs[1].ln()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn softmax_gradient_matches_finite_differences() {
+        let inputs = vec![0.3_f32, -1.2, 0.8];
+
+        for index in 0..inputs.len() {
+            let softmax = op::softmax::<f32>(index);
+            let output = softmax.apply(&inputs);
+            let gradient = derivative(&softmax, &inputs, output);
+
+            for (position, analytic) in gradient.iter().enumerate() {
+                let mut upper = inputs.clone();
+                let mut lower = inputs.clone();
+                upper[position] += 1e-3;
+                lower[position] -= 1e-3;
+
+                let numeric = (softmax.apply(&upper) - softmax.apply(&lower)) / 2e-3;
+                assert!((analytic - numeric).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn classification_scores_mismatched_outputs_as_misses() {
+        let classification =
+            Classification::<f32>::from_labels(vec![vec![0.0], vec![1.0]], vec![2, 0], 3);
+        let (matrix, log_loss) = classification.evaluate(|input| match input[0] > 0.5 {
+            true => vec![0.9, 0.05, 0.05],
+            false => vec![0.1, 0.9],
+        });
+
+        assert_eq!(matrix.total(), 2);
+        assert_eq!(matrix.accuracy(), 0.5);
+        assert!(log_loss > 8.0);
+    }
+
+    #[test]
+    fn time_series_windows_and_forecasts() {
+        let series = TimeSeries::new(
+            (0..10)
+                .map(|index| vec![index as f32, 10.0 * index as f32])
+                .collect(),
+            ErrorFunction::MAE,
+        )
+        .lag(3)
+        .horizon(2)
+        .stride(2)
+        .targets(vec![1]);
+
+        assert_eq!(series.get_samples().len(), 3);
+        assert_eq!(series.output_size(), 2);
+        assert_eq!(
+            series.get_samples()[1].1,
+            vec![2.0, 20.0, 3.0, 30.0, 4.0, 40.0]
+        );
+        assert_eq!(series.get_samples()[1].2, vec![50.0, 60.0]);
+        assert_eq!(
+            series.sequences[2],
+            vec![vec![4.0, 40.0], vec![5.0, 50.0], vec![6.0, 60.0]]
+        );
+
+        let persistence = |sequence: &[Vec<f32>]| {
+            sequence
+                .iter()
+                .map(|step| vec![step[1] + 10.0, step[1] + 20.0])
+                .collect::<Vec<Vec<f32>>>()
+        };
+        assert_eq!(series.sequence_error(persistence), 0.0);
+        assert_eq!(series.horizon_errors(persistence), vec![0.0, 0.0]);
+
+        let lagging = |sequence: &[Vec<f32>]| vec![vec![sequence[2][1], sequence[2][1]]];
+        assert_eq!(series.horizon_errors(lagging), vec![10.0, 20.0]);
+        assert_eq!(series.sequence_error(lagging), 30.0);
+        assert_eq!(
+            series.error(|input| vec![input[5] + 10.0, input[5] + 20.0]),
+            0.0
+        );
+
+        let factory = NodeFactory::<f32>::regression(1);
+        let graph = Architect::<Graph<f32>, f32>::new(&factory).lstm(1, 1, 1);
+        let pulse = TimeSeries::univariate(
+            (0..8)
+                .map(|index| (index % 4 == 3) as usize as f32)
+                .collect(),
+            ErrorFunction::MSE,
+        )
+        .lag(4);
+
+        let mut tape = graph.compile();
+        let mut reducer = GraphReducer::new(&graph);
+        let streamed = pulse.forecasts(|sequence| tape.run_sequence(sequence));
+        assert_eq!(streamed.len(), 4);
+        assert_eq!(
+            streamed,
+            pulse.forecasts(|sequence| reducer.run_sequence(sequence))
+        );
+        assert!(pulse
+            .sequence_error(|sequence| tape.run_sequence(sequence))
+            .is_finite());
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+
+    use radiate_extensions::*;
+    use radiate_rust::*;
+
+    fn instruction(
+        op_set: &Arc<Vec<Ops<f32>>>,
+        op_index: usize,
+        dst: usize,
+        sources: Vec<usize>,
+    ) -> Instruction<f32> {
+        Instruction {
+            dst,
+            sources,
+            value: op_set[op_index].clone(),
+            num_registers: 2,
+            num_inputs: 2,
+            op_set: op_set.clone(),
+        }
+    }
+
+    #[test]
+    fn program_runs_register_instructions() {
+        let op_set = ProgramCodex::new(2, 1, vec![op::add(), op::mul()]).op_set;
+
+        // r0 = x0 + x1; r1 = r0 * x0; r0 = r1 + r0
+        let program = Program::new(
+            vec![
+                instruction(&op_set, 0, 0, vec![2, 3]),
+                instruction(&op_set, 1, 1, vec![0, 2]),
+                instruction(&op_set, 0, 0, vec![1, 0]),
+            ],
+            2,
+            2,
+            1,
+        );
+
+        let output = program.run(&[2.0, 3.0]);
+
+        assert_eq!(output, vec![15.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2 inputs")]
+    fn program_rejects_mismatched_inputs() {
+        let op_set = ProgramCodex::new(2, 1, vec![op::add(), op::mul()]).op_set;
+        let program = Program::new(vec![instruction(&op_set, 0, 0, vec![2, 3])], 2, 2, 1);
+
+        program.run(&[2.0]);
+    }
+
+    #[test]
+    fn program_eliminates_introns() {
+        let op_set = ProgramCodex::new(2, 1, vec![op::add(), op::mul()]).op_set;
+
+        // r1 is overwritten before it is read and the last write to r1 is never read.
+        let program = Program::new(
+            vec![
+                instruction(&op_set, 0, 1, vec![2, 2]),
+                instruction(&op_set, 1, 1, vec![3, 3]),
+                instruction(&op_set, 0, 0, vec![1, 2]),
+                instruction(&op_set, 1, 1, vec![0, 0]),
+            ],
+            2,
+            2,
+            1,
+        );
+
+        let effective = program.eliminate_introns();
+
+        assert_eq!(program.effective_indices(), vec![1, 2]);
+        assert_eq!(effective.len(), 2);
+        assert_eq!(effective.run(&[2.0, 3.0]), program.run(&[2.0, 3.0]));
+    }
+
+    #[test]
+    fn program_alterers_keep_programs_valid() {
+        let codex = ProgramCodex::<f32>::regression(2, 1).with_length(4, 12);
+
+        let mutator = InstructionMutator::<f32>::new(0.5);
+        let crossover = ProgramCrossover::<f32>::new(1.0, 4);
+
+        for _ in 0..100 {
+            let mut one = codex.encode().get_chromosome(0).clone();
+            let mut two = codex.encode().get_chromosome(0).clone();
+
+            mutator.mutate_chromosome(&mut one, 0);
+            crossover.cross_chromosomes(&mut one, &mut two);
+
+            assert!(one.is_valid() && two.is_valid());
+        }
+    }
+
+    #[test]
+    fn program_codex_evolves_regression() {
+        let codex = ProgramCodex::<f32>::regression(1, 1)
+            .with_registers(3)
+            .with_length(2, 20);
+
+        let samples = (0..20)
+            .map(|i| {
+                let x = i as f32 / 10.0;
+                (vec![x], vec![x * x + x])
+            })
+            .collect::<Vec<(Vec<f32>, Vec<f32>)>>();
+
+        let regression = Regression::from(ErrorFunction::MSE, samples);
+
+        let engine = GeneticEngine::from_codex(&codex)
+            .minimizing()
+            .alterer(vec![
+                ProgramCrossover::alterer(0.5, 4),
+                InstructionMutator::alterer(0.1),
+                Alterer::insertion_mutator(0.05, 2),
+                Alterer::deletion_mutator(0.05, 2),
+            ])
+            .fitness_fn(move |program: Program<f32>| {
+                Score::from_f32(regression.error(|input| program.run(input)))
+            })
+            .build();
+
+        let result = engine.run(|output| output.index >= 20);
+
+        assert!(result.best.len() >= 2 && result.best.len() <= 20);
+        assert!(result.score().as_float().is_finite());
+    }
+}
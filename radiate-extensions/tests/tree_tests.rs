@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+
+    use radiate_extensions::*;
+    use radiate_rust::*;
+
+    #[test]
+    fn tree_evaluates_inputs() {
+        // (+ x0 (* x1 x1))
+        let tree = TreeNode::new(
+            op::add(),
+            vec![
+                TreeNode::leaf(op::var(0)),
+                TreeNode::new(
+                    op::mul(),
+                    vec![TreeNode::leaf(op::var(1)), TreeNode::leaf(op::var(1))],
+                ),
+            ],
+        );
+
+        assert_eq!(tree.eval(&[2.0_f32, 3.0]), 11.0);
+        assert_eq!(tree.size(), 5);
+        assert_eq!(tree.depth(), 2);
+        assert_eq!(tree.level(3), Some(2));
+        assert_eq!(format!("{}", tree), "(+ x0 (* x1 x1))");
+    }
+
+    #[test]
+    fn tree_codex_respects_max_depth() {
+        let factory = NodeFactory::<f32>::regression(2);
+        let codex = TreeCodex::new(2, 4, &factory);
+
+        for _ in 0..50 {
+            let tree = codex.decode(&codex.encode());
+
+            assert_eq!(tree.roots().len(), 2);
+            assert!(tree.depth() <= 4);
+            assert!(tree.roots().iter().all(|root| root.is_valid()));
+        }
+    }
+
+    #[test]
+    fn tree_alterers_enforce_depth_limit() {
+        let factory = NodeFactory::<f32>::regression(1);
+        let codex = TreeCodex::new(1, 5, &factory);
+
+        let crossover = SubtreeCrossover::<f32>::new(1.0, 5);
+        let mutator = TreeMutator::new(
+            factory.clone(),
+            5,
+            vec![
+                TreeMutate::Subtree(0.5),
+                TreeMutate::Point(0.5),
+                TreeMutate::Hoist(0.1),
+                TreeMutate::Shrink(0.1),
+            ],
+        );
+
+        for _ in 0..100 {
+            let mut one = codex.encode().get_chromosome(0).clone();
+            let mut two = codex.encode().get_chromosome(0).clone();
+
+            crossover.cross_chromosomes(&mut one, &mut two);
+            mutator.mutate_chromosome(&mut one, 0);
+
+            for tree in one.iter().chain(two.iter()) {
+                assert!(tree.depth() <= 5);
+                assert!(tree.is_valid());
+            }
+        }
+    }
+
+    #[test]
+    fn tree_codex_evolves_regression() {
+        let factory = NodeFactory::<f32>::regression(1);
+        let codex = TreeCodex::new(1, 5, &factory);
+
+        let samples = (0..20)
+            .map(|i| {
+                let x = i as f32 / 10.0;
+                (vec![x], vec![x * x + x])
+            })
+            .collect::<Vec<(Vec<f32>, Vec<f32>)>>();
+
+        let regression = Regression::from(ErrorFunction::MSE, samples);
+
+        let engine = GeneticEngine::from_codex(&codex)
+            .minimizing()
+            .alterer(vec![
+                SubtreeCrossover::alterer(0.5, 5),
+                TreeMutator::alterer(
+                    factory.clone(),
+                    5,
+                    vec![TreeMutate::Subtree(0.1), TreeMutate::Point(0.1)],
+                ),
+            ])
+            .fitness_fn(move |tree: Tree<f32>| {
+                Score::from_f32(regression.error(|input| tree.eval(input)))
+            })
+            .build();
+
+        let result = engine.run(|output| output.index >= 20);
+
+        assert!(result.best.depth() <= 5);
+        assert!(result.score().as_float().is_finite());
+    }
+}
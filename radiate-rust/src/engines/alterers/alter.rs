@@ -1,9 +1,22 @@
-use crate::engines::genome::genes::gene::Gene;
+use crate::engines::genome::genes::float_gene::FloatGene;
+use crate::engines::genome::genes::gene::{Gene, NumericGene};
 use crate::engines::genome::population::Population;
 use crate::engines::optimize::Optimize;
 
+use super::crossovers::arithmetic_crossover::{ArithmeticCrossover, SingleArithmeticCrossover};
+use super::crossovers::blend_crossover::BlendCrossover;
 use super::crossovers::crossover::Crossover;
+use super::crossovers::cut_and_splice_crossover::CutAndSpliceCrossover;
+use super::crossovers::line_crossover::LineCrossover;
+use super::crossovers::sbx_crossover::SimulatedBinaryCrossover;
+use super::mutators::cauchy_mutator::CauchyMutator;
+use super::mutators::creep_mutator::CreepMutator;
+use super::mutators::deletion_mutator::DeletionMutator;
+use super::mutators::duplication_mutator::DuplicationMutator;
+use super::mutators::gaussian_mutator::GaussianMutator;
+use super::mutators::insertion_mutator::InsertionMutator;
 use super::mutators::mutate::Mutate;
+use super::mutators::polynomial_mutator::PolynomialMutator;
 
 pub trait Alter<G, A>
 where
@@ -60,4 +73,63 @@ where
     {
         Alterer::Mutation(Box::new(mutation))
     }
+
+    pub fn insertion_mutator(rate: f32, max_segment: usize) -> Self {
+        Alterer::mutation(InsertionMutator::new(rate, max_segment))
+    }
+
+    pub fn deletion_mutator(rate: f32, max_segment: usize) -> Self {
+        Alterer::mutation(DeletionMutator::new(rate, max_segment))
+    }
+
+    pub fn duplication_mutator(rate: f32, max_segment: usize) -> Self {
+        Alterer::mutation(DuplicationMutator::new(rate, max_segment))
+    }
+
+    pub fn cut_and_splice_crossover(rate: f32) -> Self {
+        Alterer::crossover(CutAndSpliceCrossover::new(rate))
+    }
+}
+
+impl<G, A> Alterer<G, A>
+where
+    G: NumericGene<G, A>,
+{
+    pub fn gaussian_mutator(rate: f32, sigma: f32) -> Self {
+        Alterer::mutation(GaussianMutator::new(rate, sigma))
+    }
+
+    pub fn cauchy_mutator(rate: f32, scale: f32) -> Self {
+        Alterer::mutation(CauchyMutator::new(rate, scale))
+    }
+
+    pub fn polynomial_mutator(rate: f32, distribution_index: f32) -> Self {
+        Alterer::mutation(PolynomialMutator::new(rate, distribution_index))
+    }
+
+    pub fn creep_mutator(rate: f32, step: i32) -> Self {
+        Alterer::mutation(CreepMutator::new(rate, step))
+    }
+}
+
+impl Alterer<FloatGene, f32> {
+    pub fn sbx_crossover(rate: f32, distribution_index: f32) -> Self {
+        Alterer::crossover(SimulatedBinaryCrossover::new(rate, distribution_index))
+    }
+
+    pub fn blend_crossover(rate: f32, alpha: f32) -> Self {
+        Alterer::crossover(BlendCrossover::new(rate, alpha))
+    }
+
+    pub fn arithmetic_crossover(rate: f32) -> Self {
+        Alterer::crossover(ArithmeticCrossover::new(rate))
+    }
+
+    pub fn single_arithmetic_crossover(rate: f32) -> Self {
+        Alterer::crossover(SingleArithmeticCrossover::new(rate))
+    }
+
+    pub fn line_crossover(rate: f32, extension: f32) -> Self {
+        Alterer::crossover(LineCrossover::new(rate, extension))
+    }
 }
@@ -130,7 +130,8 @@ where
                 None => (),
             };
             match alterer.crossover {
-                Some(ref crossover) => {
+                // A single individual has no mate, and subset(1, 2) would panic.
+                Some(ref crossover) if population.len() > 1 => {
                     let mut random = rand::thread_rng();
 
                     for i in 0..population.len() {
@@ -141,7 +142,7 @@ where
                         }
                     }
                 }
-                None => (),
+                _ => (),
             };
             match alterer.alterer {
                 Some(ref alterer) => {
@@ -0,0 +1,108 @@
+use rand::Rng;
+
+use crate::Chromosome;
+use crate::FloatGene;
+
+use super::Crossover;
+
+pub struct ArithmeticCrossover {
+    pub rate: f32,
+}
+
+impl ArithmeticCrossover {
+    pub fn new(rate: f32) -> Self {
+        ArithmeticCrossover { rate }
+    }
+
+    #[inline]
+    pub fn blend(
+        chrom_one: &mut Chromosome<FloatGene, f32>,
+        chrom_two: &mut Chromosome<FloatGene, f32>,
+        index: usize,
+        alpha: f32,
+    ) -> bool {
+        let gene_one = chrom_one.get_gene(index);
+        let gene_two = chrom_two.get_gene(index);
+
+        if gene_one.allele == gene_two.allele {
+            return false;
+        }
+
+        let new_one = alpha * gene_one.allele + (1_f32 - alpha) * gene_two.allele;
+        let new_two = (1_f32 - alpha) * gene_one.allele + alpha * gene_two.allele;
+
+        let new_gene_one = gene_one.clamp(new_one);
+        let new_gene_two = gene_two.clamp(new_two);
+
+        chrom_one.set_gene(index, new_gene_one);
+        chrom_two.set_gene(index, new_gene_two);
+
+        true
+    }
+}
+
+impl Crossover<FloatGene, f32> for ArithmeticCrossover {
+    fn cross_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn cross_chromosomes(
+        &self,
+        chrom_one: &mut Chromosome<FloatGene, f32>,
+        chrom_two: &mut Chromosome<FloatGene, f32>,
+    ) -> i32 {
+        let mut random = rand::thread_rng();
+        let alpha = random.gen::<f32>();
+        let mut count = 0;
+
+        for i in 0..std::cmp::min(chrom_one.len(), chrom_two.len()) {
+            if random.gen::<f32>() >= self.rate {
+                continue;
+            }
+
+            if ArithmeticCrossover::blend(chrom_one, chrom_two, i, alpha) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+pub struct SingleArithmeticCrossover {
+    pub rate: f32,
+}
+
+impl SingleArithmeticCrossover {
+    pub fn new(rate: f32) -> Self {
+        SingleArithmeticCrossover { rate }
+    }
+}
+
+impl Crossover<FloatGene, f32> for SingleArithmeticCrossover {
+    fn cross_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn cross_chromosomes(
+        &self,
+        chrom_one: &mut Chromosome<FloatGene, f32>,
+        chrom_two: &mut Chromosome<FloatGene, f32>,
+    ) -> i32 {
+        let length = std::cmp::min(chrom_one.len(), chrom_two.len());
+        if length == 0 {
+            return 0;
+        }
+
+        let mut random = rand::thread_rng();
+        let index = random.gen_range(0..length);
+        let alpha = random.gen::<f32>();
+
+        match ArithmeticCrossover::blend(chrom_one, chrom_two, index, alpha) {
+            true => 1,
+            false => 0,
+        }
+    }
+}
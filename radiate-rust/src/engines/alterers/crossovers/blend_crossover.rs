@@ -0,0 +1,62 @@
+use rand::Rng;
+
+use crate::Chromosome;
+use crate::FloatGene;
+
+use super::Crossover;
+
+pub struct BlendCrossover {
+    pub rate: f32,
+    pub alpha: f32,
+}
+
+impl BlendCrossover {
+    pub fn new(rate: f32, alpha: f32) -> Self {
+        BlendCrossover { rate, alpha }
+    }
+}
+
+impl Crossover<FloatGene, f32> for BlendCrossover {
+    fn cross_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn cross_chromosomes(
+        &self,
+        chrom_one: &mut Chromosome<FloatGene, f32>,
+        chrom_two: &mut Chromosome<FloatGene, f32>,
+    ) -> i32 {
+        let mut random = rand::thread_rng();
+        let mut count = 0;
+
+        for i in 0..std::cmp::min(chrom_one.len(), chrom_two.len()) {
+            if random.gen::<f32>() >= self.rate {
+                continue;
+            }
+
+            let gene_one = chrom_one.get_gene(i);
+            let gene_two = chrom_two.get_gene(i);
+
+            let low = gene_one.allele.min(gene_two.allele);
+            let high = gene_one.allele.max(gene_two.allele);
+
+            if high <= low {
+                continue;
+            }
+
+            let extent = self.alpha * (high - low);
+            let range = low - extent..=high + extent;
+
+            let new_gene_one = gene_one.clamp(random.gen_range(range.clone()));
+            let new_gene_two = gene_two.clamp(random.gen_range(range));
+
+            chrom_one.set_gene(i, new_gene_one);
+            chrom_two.set_gene(i, new_gene_two);
+
+            count += 1;
+        }
+
+        count
+    }
+}
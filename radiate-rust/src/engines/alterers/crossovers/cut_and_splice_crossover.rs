@@ -0,0 +1,59 @@
+use rand::Rng;
+
+use crate::engines::alterers::crossovers::crossover::Crossover;
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::Gene;
+
+const MAX_CUT_ATTEMPTS: usize = 10;
+
+pub struct CutAndSpliceCrossover {
+    pub rate: f32,
+}
+
+impl CutAndSpliceCrossover {
+    pub fn new(rate: f32) -> Self {
+        Self { rate }
+    }
+}
+
+impl<G: Gene<G, A>, A> Crossover<G, A> for CutAndSpliceCrossover {
+    fn cross_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn cross_chromosomes(
+        &self,
+        chrom_one: &mut Chromosome<G, A>,
+        chrom_two: &mut Chromosome<G, A>,
+    ) -> i32 {
+        let mut random = rand::thread_rng();
+        let length_one = chrom_one.len();
+        let length_two = chrom_two.len();
+
+        for _ in 0..MAX_CUT_ATTEMPTS {
+            let cut_one = random.gen_range(0..=length_one);
+            let cut_two = random.gen_range(0..=length_two);
+
+            let new_length_one = cut_one + (length_two - cut_two);
+            let new_length_two = cut_two + (length_one - cut_one);
+
+            if !chrom_one.is_length_valid(new_length_one)
+                || !chrom_two.is_length_valid(new_length_two)
+            {
+                continue;
+            }
+
+            let tail_one = chrom_one.remove_genes(cut_one, length_one);
+            let tail_two = chrom_two.remove_genes(cut_two, length_two);
+            let cross_count = (tail_one.len() + tail_two.len()) as i32;
+
+            chrom_one.insert_genes(cut_one, tail_two);
+            chrom_two.insert_genes(cut_two, tail_one);
+
+            return cross_count;
+        }
+
+        0
+    }
+}
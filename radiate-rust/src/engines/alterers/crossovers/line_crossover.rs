@@ -0,0 +1,67 @@
+use rand::Rng;
+
+use crate::Chromosome;
+use crate::FloatGene;
+
+use super::Crossover;
+
+const DEFAULT_EXTENSION: f32 = 0.25;
+
+pub struct LineCrossover {
+    pub rate: f32,
+    pub extension: f32,
+}
+
+impl LineCrossover {
+    pub fn new(rate: f32, extension: f32) -> Self {
+        LineCrossover { rate, extension }
+    }
+
+    pub fn with_rate(rate: f32) -> Self {
+        LineCrossover::new(rate, DEFAULT_EXTENSION)
+    }
+}
+
+impl Crossover<FloatGene, f32> for LineCrossover {
+    fn cross_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn cross_chromosomes(
+        &self,
+        chrom_one: &mut Chromosome<FloatGene, f32>,
+        chrom_two: &mut Chromosome<FloatGene, f32>,
+    ) -> i32 {
+        let mut random = rand::thread_rng();
+        let mut count = 0;
+
+        let range = -self.extension..=1_f32 + self.extension;
+        let alpha_one = random.gen_range(range.clone());
+        let alpha_two = random.gen_range(range);
+
+        for i in 0..std::cmp::min(chrom_one.len(), chrom_two.len()) {
+            if random.gen::<f32>() >= self.rate {
+                continue;
+            }
+
+            let gene_one = chrom_one.get_gene(i);
+            let gene_two = chrom_two.get_gene(i);
+
+            let diff = gene_two.allele - gene_one.allele;
+            if diff == 0_f32 {
+                continue;
+            }
+
+            let new_gene_one = gene_one.clamp(gene_one.allele + alpha_one * diff);
+            let new_gene_two = gene_two.clamp(gene_two.allele - alpha_two * diff);
+
+            chrom_one.set_gene(i, new_gene_one);
+            chrom_two.set_gene(i, new_gene_two);
+
+            count += 1;
+        }
+
+        count
+    }
+}
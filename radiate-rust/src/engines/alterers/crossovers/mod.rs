@@ -1,9 +1,19 @@
+pub mod arithmetic_crossover;
+pub mod blend_crossover;
 pub mod crossover;
+pub mod cut_and_splice_crossover;
+pub mod line_crossover;
 pub mod mean_crossover;
 pub mod multipoint_crossover;
+pub mod sbx_crossover;
 pub mod uniform_crossover;
 
+pub use arithmetic_crossover::{ArithmeticCrossover, SingleArithmeticCrossover};
+pub use blend_crossover::BlendCrossover;
 pub use crossover::Crossover;
+pub use cut_and_splice_crossover::CutAndSpliceCrossover;
+pub use line_crossover::LineCrossover;
 pub use mean_crossover::MeanCrossover;
 pub use multipoint_crossover::MultiPointCrossover;
+pub use sbx_crossover::SimulatedBinaryCrossover;
 pub use uniform_crossover::UniformCrossover;
@@ -23,7 +23,11 @@ impl MultiPointCrossover {
         chrom_two: &mut Chromosome<G, A>,
         other_start: usize,
     ) {
-        if other_start + (end - start) > chrom_one.len() {
+        if start >= end {
+            return;
+        }
+
+        if end > chrom_one.len() || other_start + (end - start) > chrom_two.len() {
             panic!(
                 "Invalid index range: [{}, {})",
                 other_start,
@@ -31,11 +35,7 @@ impl MultiPointCrossover {
             );
         }
 
-        if start >= end {
-            return;
-        }
-
-        for i in (end - start..0).rev() {
+        for i in (0..end - start).rev() {
             let temp = chrom_one.get_gene(start + i);
             let other_gene = chrom_two.get_gene(other_start + i);
 
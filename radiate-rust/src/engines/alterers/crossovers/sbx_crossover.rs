@@ -0,0 +1,101 @@
+use rand::random;
+
+use crate::Chromosome;
+use crate::FloatGene;
+
+use super::Crossover;
+
+const DEFAULT_DISTRIBUTION_INDEX: f32 = 20_f32;
+const MIN_DIFFERENCE: f32 = 1e-7_f32;
+
+pub struct SimulatedBinaryCrossover {
+    pub rate: f32,
+    pub distribution_index: f32,
+}
+
+impl SimulatedBinaryCrossover {
+    pub fn new(rate: f32, distribution_index: f32) -> Self {
+        SimulatedBinaryCrossover {
+            rate,
+            distribution_index,
+        }
+    }
+
+    pub fn with_rate(rate: f32) -> Self {
+        SimulatedBinaryCrossover::new(rate, DEFAULT_DISTRIBUTION_INDEX)
+    }
+
+    #[inline]
+    fn spread(&self, beta: f32, rand: f32) -> f32 {
+        let exponent = 1_f32 / (self.distribution_index + 1_f32);
+        let alpha = 2_f32 - beta.powf(-(self.distribution_index + 1_f32));
+
+        if rand <= 1_f32 / alpha {
+            (rand * alpha).powf(exponent)
+        } else {
+            (1_f32 / (2_f32 - rand * alpha)).powf(exponent)
+        }
+    }
+}
+
+impl Crossover<FloatGene, f32> for SimulatedBinaryCrossover {
+    fn cross_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn cross_chromosomes(
+        &self,
+        chrom_one: &mut Chromosome<FloatGene, f32>,
+        chrom_two: &mut Chromosome<FloatGene, f32>,
+    ) -> i32 {
+        let mut count = 0;
+
+        for i in 0..std::cmp::min(chrom_one.len(), chrom_two.len()) {
+            if random::<f32>() >= self.rate {
+                continue;
+            }
+
+            let gene_one = chrom_one.get_gene(i);
+            let gene_two = chrom_two.get_gene(i);
+
+            let (x_one, x_two) = (gene_one.allele, gene_two.allele);
+            if (x_one - x_two).abs() < MIN_DIFFERENCE {
+                continue;
+            }
+
+            let (low, high) = if x_one < x_two {
+                (x_one, x_two)
+            } else {
+                (x_two, x_one)
+            };
+
+            let lower = gene_one.min.max(gene_one.lower_bound);
+            let upper = gene_one.max.min(gene_one.upper_bound);
+            let diff = high - low;
+            let rand = random::<f32>();
+
+            let beta_low = 1_f32 + 2_f32 * (low - lower).max(0_f32) / diff;
+            let beta_high = 1_f32 + 2_f32 * (upper - high).max(0_f32) / diff;
+
+            let child_low = 0.5 * ((low + high) - self.spread(beta_low, rand) * diff);
+            let child_high = 0.5 * ((low + high) + self.spread(beta_high, rand) * diff);
+
+            let (child_one, child_two) = if random::<bool>() {
+                (child_low, child_high)
+            } else {
+                (child_high, child_low)
+            };
+
+            let new_gene_one = gene_one.clamp(child_one);
+            let new_gene_two = gene_two.clamp(child_two);
+
+            chrom_one.set_gene(i, new_gene_one);
+            chrom_two.set_gene(i, new_gene_two);
+
+            count += 1;
+        }
+
+        count
+    }
+}
@@ -0,0 +1,56 @@
+use rand::Rng;
+
+use crate::engines::alterers::mutators::gaussian_mutator::Sigma;
+use crate::engines::alterers::mutators::mutate::Mutate;
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::NumericGene;
+
+pub struct CauchyMutator {
+    pub rate: f32,
+    pub scale: Sigma,
+}
+
+impl CauchyMutator {
+    pub fn new(rate: f32, scale: f32) -> Self {
+        Self {
+            rate,
+            scale: Sigma::Fixed(scale),
+        }
+    }
+
+    pub fn scaled(rate: f32, scale: f32) -> Self {
+        Self {
+            rate,
+            scale: Sigma::Scaled(scale),
+        }
+    }
+}
+
+impl<G, A> Mutate<G, A> for CauchyMutator
+where
+    G: NumericGene<G, A>,
+{
+    fn mutate_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<G, A>, range: i32) -> i32 {
+        let mut random = rand::thread_rng();
+        let mut mutations = 0;
+
+        for gene in chromosome.iter_mut() {
+            if rand::random::<i32>() < range {
+                let (lower, upper) = gene.limits();
+                let scale = self.scale.value(gene);
+                let jump = (std::f64::consts::PI * (random.gen::<f64>() - 0.5)).tan();
+                let value = gene.as_f64() + scale * jump;
+
+                *gene = gene.with_f64(value.max(lower).min(upper));
+                mutations += 1;
+            }
+        }
+
+        mutations
+    }
+}
@@ -0,0 +1,47 @@
+use rand::Rng;
+
+use crate::engines::alterers::mutators::mutate::Mutate;
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::NumericGene;
+
+pub struct CreepMutator {
+    pub rate: f32,
+    pub step: i32,
+}
+
+impl CreepMutator {
+    pub fn new(rate: f32, step: i32) -> Self {
+        Self {
+            rate,
+            step: step.max(1),
+        }
+    }
+}
+
+impl<G, A> Mutate<G, A> for CreepMutator
+where
+    G: NumericGene<G, A>,
+{
+    fn mutate_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<G, A>, range: i32) -> i32 {
+        let mut random = rand::thread_rng();
+        let mut mutations = 0;
+
+        for gene in chromosome.iter_mut() {
+            if rand::random::<i32>() < range {
+                let step = random.gen_range(1..=self.step);
+                *gene = match random.gen::<bool>() {
+                    true => gene.creep(step),
+                    false => gene.creep(-step),
+                };
+                mutations += 1;
+            }
+        }
+
+        mutations
+    }
+}
@@ -0,0 +1,46 @@
+use rand::Rng;
+
+use crate::engines::alterers::mutators::mutate::Mutate;
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::Gene;
+
+pub struct DeletionMutator {
+    pub rate: f32,
+    pub max_segment: usize,
+}
+
+impl DeletionMutator {
+    pub fn new(rate: f32, max_segment: usize) -> Self {
+        Self {
+            rate,
+            max_segment: max_segment.max(1),
+        }
+    }
+}
+
+impl<G: Gene<G, A>, A> Mutate<G, A> for DeletionMutator {
+    fn mutate_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<G, A>, _: i32) -> i32 {
+        let mut random = rand::thread_rng();
+        let length = chromosome.len();
+
+        if length == 0 || random.gen::<f32>() >= self.rate {
+            return 0;
+        }
+
+        let room = length.saturating_sub(chromosome.min_length);
+        let count = std::cmp::min(random.gen_range(1..=self.max_segment), room);
+
+        if count == 0 {
+            return 0;
+        }
+
+        let start = random.gen_range(0..=length - count);
+        chromosome.remove_genes(start, start + count);
+        count as i32
+    }
+}
@@ -0,0 +1,61 @@
+use rand::Rng;
+
+use crate::engines::alterers::mutators::mutate::Mutate;
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::Gene;
+
+pub struct DuplicationMutator {
+    pub rate: f32,
+    pub max_segment: usize,
+}
+
+impl DuplicationMutator {
+    pub fn new(rate: f32, max_segment: usize) -> Self {
+        Self {
+            rate,
+            max_segment: max_segment.max(1),
+        }
+    }
+}
+
+impl<G: Gene<G, A>, A> Mutate<G, A> for DuplicationMutator {
+    fn mutate_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<G, A>, _: i32) -> i32 {
+        let mut random = rand::thread_rng();
+        let length = chromosome.len();
+
+        if random.gen::<f32>() >= self.rate {
+            return 0;
+        }
+
+        let room = chromosome.max_length.saturating_sub(length);
+        let count = std::cmp::min(random.gen_range(1..=self.max_segment), room);
+
+        if length == 0 {
+            return match chromosome.template() {
+                Some(template) if count > 0 => {
+                    let genes = (0..count).map(|_| template.new_instance()).collect();
+                    chromosome.insert_genes(0, genes);
+                    count as i32
+                }
+                _ => 0,
+            };
+        }
+
+        let count = std::cmp::min(count, length);
+
+        if count == 0 {
+            return 0;
+        }
+
+        let start = random.gen_range(0..=length - count);
+        let segment = chromosome.get_genes()[start..start + count].to_vec();
+
+        chromosome.insert_genes(random.gen_range(0..=length), segment);
+        count as i32
+    }
+}
@@ -0,0 +1,83 @@
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+use crate::engines::alterers::mutators::mutate::Mutate;
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::NumericGene;
+
+pub enum Sigma {
+    Fixed(f32),
+    Scaled(f32),
+}
+
+impl Sigma {
+    pub fn value<G, A>(&self, gene: &G) -> f64
+    where
+        G: NumericGene<G, A>,
+    {
+        match self {
+            Sigma::Fixed(sigma) => *sigma as f64,
+            Sigma::Scaled(scale) => {
+                let (lower, upper) = gene.limits();
+                *scale as f64 * (upper - lower)
+            }
+        }
+    }
+}
+
+pub struct GaussianMutator {
+    pub rate: f32,
+    pub sigma: Sigma,
+}
+
+impl GaussianMutator {
+    pub fn new(rate: f32, sigma: f32) -> Self {
+        Self {
+            rate,
+            sigma: Sigma::Fixed(sigma),
+        }
+    }
+
+    pub fn scaled(rate: f32, scale: f32) -> Self {
+        Self {
+            rate,
+            sigma: Sigma::Scaled(scale),
+        }
+    }
+
+    #[inline]
+    pub fn standard_normal(random: &mut ThreadRng) -> f64 {
+        let one = random.gen::<f64>().max(f64::MIN_POSITIVE);
+        let two = random.gen::<f64>();
+
+        (-2_f64 * one.ln()).sqrt() * (2_f64 * std::f64::consts::PI * two).cos()
+    }
+}
+
+impl<G, A> Mutate<G, A> for GaussianMutator
+where
+    G: NumericGene<G, A>,
+{
+    fn mutate_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<G, A>, range: i32) -> i32 {
+        let mut random = rand::thread_rng();
+        let mut mutations = 0;
+
+        for gene in chromosome.iter_mut() {
+            if rand::random::<i32>() < range {
+                let (lower, upper) = gene.limits();
+                let sigma = self.sigma.value(gene);
+                let value = gene.as_f64() + sigma * GaussianMutator::standard_normal(&mut random);
+
+                *gene = gene.with_f64(value.max(lower).min(upper));
+                mutations += 1;
+            }
+        }
+
+        mutations
+    }
+}
@@ -0,0 +1,60 @@
+use rand::Rng;
+
+use crate::engines::alterers::mutators::mutate::Mutate;
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::Gene;
+
+pub struct InsertionMutator {
+    pub rate: f32,
+    pub max_segment: usize,
+}
+
+impl InsertionMutator {
+    pub fn new(rate: f32, max_segment: usize) -> Self {
+        Self {
+            rate,
+            max_segment: max_segment.max(1),
+        }
+    }
+}
+
+impl<G: Gene<G, A>, A> Mutate<G, A> for InsertionMutator {
+    fn mutate_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<G, A>, _: i32) -> i32 {
+        let mut random = rand::thread_rng();
+        let length = chromosome.len();
+
+        if random.gen::<f32>() >= self.rate {
+            return 0;
+        }
+
+        let room = chromosome.max_length.saturating_sub(length);
+        let count = std::cmp::min(random.gen_range(1..=self.max_segment), room);
+
+        if count == 0 {
+            return 0;
+        }
+
+        let index = random.gen_range(0..=length);
+        let genes = match length {
+            0 => match chromosome.template() {
+                Some(template) => (0..count).map(|_| template.new_instance()).collect(),
+                None => return 0,
+            },
+            _ => (0..count)
+                .map(|_| {
+                    chromosome
+                        .get_gene(random.gen_range(0..length))
+                        .new_instance()
+                })
+                .collect::<Vec<G>>(),
+        };
+
+        chromosome.insert_genes(index, genes);
+        count as i32
+    }
+}
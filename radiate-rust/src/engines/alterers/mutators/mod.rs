@@ -1,9 +1,23 @@
+pub mod cauchy_mutator;
+pub mod creep_mutator;
+pub mod deletion_mutator;
+pub mod duplication_mutator;
+pub mod gaussian_mutator;
+pub mod insertion_mutator;
 pub mod mutate;
 pub mod mutator;
 pub mod numeric_mutator;
+pub mod polynomial_mutator;
 pub mod swap_mutator;
 
+pub use cauchy_mutator::CauchyMutator;
+pub use creep_mutator::CreepMutator;
+pub use deletion_mutator::DeletionMutator;
+pub use duplication_mutator::DuplicationMutator;
+pub use gaussian_mutator::{GaussianMutator, Sigma};
+pub use insertion_mutator::InsertionMutator;
 pub use mutate::Mutate;
 pub use mutator::Mutator;
 pub use numeric_mutator::NumericMutator;
+pub use polynomial_mutator::PolynomialMutator;
 pub use swap_mutator::SwapMutator;
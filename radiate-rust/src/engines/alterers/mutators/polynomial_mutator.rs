@@ -0,0 +1,72 @@
+use rand::Rng;
+
+use crate::engines::alterers::mutators::mutate::Mutate;
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::NumericGene;
+
+pub struct PolynomialMutator {
+    pub rate: f32,
+    pub distribution_index: f32,
+}
+
+impl PolynomialMutator {
+    pub fn new(rate: f32, distribution_index: f32) -> Self {
+        Self {
+            rate,
+            distribution_index,
+        }
+    }
+
+    #[inline]
+    pub fn perturb(&self, value: f64, lower: f64, upper: f64, rand: f64) -> f64 {
+        let distribution_index = self.distribution_index as f64;
+        let range = upper - lower;
+        if range <= 0_f64 {
+            return value;
+        }
+
+        let delta_one = (value - lower) / range;
+        let delta_two = (upper - value) / range;
+        let power = 1_f64 / (distribution_index + 1_f64);
+
+        let delta = if rand < 0.5 {
+            let base = 1_f64 - delta_one;
+            let val = 2_f64 * rand + (1_f64 - 2_f64 * rand) * base.powf(distribution_index + 1_f64);
+            val.powf(power) - 1_f64
+        } else {
+            let base = 1_f64 - delta_two;
+            let val = 2_f64 * (1_f64 - rand)
+                + 2_f64 * (rand - 0.5) * base.powf(distribution_index + 1_f64);
+            1_f64 - val.powf(power)
+        };
+
+        (value + delta * range).max(lower).min(upper)
+    }
+}
+
+impl<G, A> Mutate<G, A> for PolynomialMutator
+where
+    G: NumericGene<G, A>,
+{
+    fn mutate_rate(&self) -> f32 {
+        self.rate
+    }
+
+    #[inline]
+    fn mutate_chromosome(&self, chromosome: &mut Chromosome<G, A>, range: i32) -> i32 {
+        let mut random = rand::thread_rng();
+        let mut mutations = 0;
+
+        for gene in chromosome.iter_mut() {
+            if rand::random::<i32>() < range {
+                let (lower, upper) = gene.limits();
+                let value = self.perturb(gene.as_f64(), lower, upper, random.gen::<f64>());
+
+                *gene = gene.with_f64(value);
+                mutations += 1;
+            }
+        }
+
+        mutations
+    }
+}
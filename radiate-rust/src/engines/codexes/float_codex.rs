@@ -48,7 +48,7 @@ impl Codex<FloatGene, f32, Vec<Vec<f32>>> for FloatCodex {
                             .into_iter()
                             .map(|_| {
                                 FloatGene::new(self.min, self.max)
-                                    .with_bounds(self.lower_bound, self.upper_bound)
+                                    .with_bounds(self.upper_bound, self.lower_bound)
                             })
                             .collect::<Vec<FloatGene>>(),
                     )
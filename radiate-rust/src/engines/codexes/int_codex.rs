@@ -45,7 +45,7 @@ impl<T: Integer<T>> Codex<IntGene<T>, T, Vec<Vec<T>>> for IntCodex<T> {
                             .into_iter()
                             .map(|_| {
                                 IntGene::new(self.min, self.max)
-                                    .with_bounds(self.lower_bound, self.upper_bound)
+                                    .with_bounds(self.upper_bound, self.lower_bound)
                             })
                             .collect::<Vec<IntGene<T>>>(),
                     )
@@ -9,6 +9,7 @@ pub mod float_codex;
 pub mod generic_codex;
 pub mod int_codex;
 pub mod subset_codex;
+pub mod variable_codex;
 
 pub use bit_codex::*;
 pub use char_codex::*;
@@ -16,6 +17,7 @@ pub use float_codex::*;
 pub use generic_codex::*;
 pub use int_codex::*;
 pub use subset_codex::*;
+pub use variable_codex::*;
 
 pub trait Codex<G, A, T>
 where
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::engines::genome::chromosome::Chromosome;
+use crate::engines::genome::genes::gene::Gene;
+use crate::engines::genome::genotype::Genotype;
+
+use super::Codex;
+
+pub struct VariableCodex<G, A>
+where
+    G: Gene<G, A>,
+{
+    pub num_chromosomes: usize,
+    pub min_length: usize,
+    pub max_length: usize,
+    pub supplier: Arc<dyn Fn() -> G>,
+    _allele: std::marker::PhantomData<A>,
+}
+
+impl<G, A> VariableCodex<G, A>
+where
+    G: Gene<G, A>,
+{
+    pub fn new(
+        num_chromosomes: usize,
+        min_length: usize,
+        max_length: usize,
+        supplier: Arc<dyn Fn() -> G>,
+    ) -> Self {
+        let (min_length, max_length) = if min_length > max_length {
+            (max_length, min_length)
+        } else {
+            (min_length, max_length)
+        };
+
+        VariableCodex {
+            num_chromosomes,
+            min_length,
+            max_length,
+            supplier,
+            _allele: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<G, A> Codex<G, A, Vec<Vec<A>>> for VariableCodex<G, A>
+where
+    G: Gene<G, A>,
+    A: Clone,
+{
+    fn encode(&self) -> Genotype<G, A> {
+        let mut random = rand::thread_rng();
+
+        Genotype {
+            chromosomes: (0..self.num_chromosomes)
+                .map(|_| {
+                    let length = random.gen_range(self.min_length..=self.max_length);
+
+                    Chromosome::from_genes(
+                        (0..length).map(|_| (self.supplier)()).collect::<Vec<G>>(),
+                    )
+                    .with_length_bounds(self.min_length, self.max_length)
+                    .with_template((self.supplier)())
+                })
+                .collect::<Vec<Chromosome<G, A>>>(),
+        }
+    }
+
+    fn decode(&self, genotype: &Genotype<G, A>) -> Vec<Vec<A>> {
+        genotype
+            .iter()
+            .map(|chromosome| {
+                chromosome
+                    .iter()
+                    .map(|gene| gene.allele().clone())
+                    .collect::<Vec<A>>()
+            })
+            .collect::<Vec<Vec<A>>>()
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use rand::seq::index::sample;
+use rand::Rng;
+
+pub type MatchFn<T> = Arc<dyn Fn(&T, &T) -> (f32, f32)>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Matchup {
+    RoundRobin,
+    RandomOpponents(usize),
+    HallOfFame(usize),
+    HostParasite(usize),
+}
+
+pub struct Competition<T> {
+    pub match_fn: MatchFn<T>,
+    pub matchup: Matchup,
+    pub hall_of_fame_size: usize,
+}
+
+impl<T> Competition<T> {
+    pub fn new(matchup: Matchup, match_fn: impl Fn(&T, &T) -> (f32, f32) + 'static) -> Self {
+        Competition {
+            match_fn: Arc::new(match_fn),
+            matchup,
+            hall_of_fame_size: 10,
+        }
+    }
+
+    pub fn hall_of_fame_size(mut self, hall_of_fame_size: usize) -> Self {
+        self.hall_of_fame_size = hall_of_fame_size.max(1);
+        self
+    }
+
+    pub fn play(&self, one: &T, two: &T) -> (f32, f32) {
+        (self.match_fn)(one, two)
+    }
+
+    pub fn has_opponents(&self) -> bool {
+        matches!(self.matchup, Matchup::HostParasite(_))
+    }
+
+    pub fn compete(&self, players: &[T], opponents: &[T]) -> (Vec<f32>, Vec<f32>) {
+        let mut random = rand::thread_rng();
+        let mut totals = vec![0_f32; players.len()];
+        let mut games = vec![0_usize; players.len()];
+        let mut opponent_totals = vec![0_f32; opponents.len()];
+        let mut opponent_games = vec![0_usize; opponents.len()];
+
+        match self.matchup {
+            Matchup::RoundRobin => {
+                for one in 0..players.len() {
+                    for two in one + 1..players.len() {
+                        let (first, second) = self.play(&players[one], &players[two]);
+                        totals[one] += first;
+                        totals[two] += second;
+                        games[one] += 1;
+                        games[two] += 1;
+                    }
+                }
+            }
+            Matchup::RandomOpponents(count) => {
+                let count = count.min(players.len().saturating_sub(1));
+                for one in 0..players.len() {
+                    for index in sample(&mut random, players.len() - 1, count).into_iter() {
+                        let two = if index >= one { index + 1 } else { index };
+                        let (first, second) = self.play(&players[one], &players[two]);
+                        totals[one] += first;
+                        totals[two] += second;
+                        games[one] += 1;
+                        games[two] += 1;
+                    }
+                }
+            }
+            Matchup::HallOfFame(count) | Matchup::HostParasite(count) => {
+                let count = count.min(opponents.len());
+                for one in 0..players.len() {
+                    for two in sample(&mut random, opponents.len(), count).into_iter() {
+                        let (first, second) = self.play(&players[one], &opponents[two]);
+                        totals[one] += first;
+                        games[one] += 1;
+                        opponent_totals[two] += second;
+                        opponent_games[two] += 1;
+                    }
+                }
+
+                if opponents.is_empty() && players.len() > 1 {
+                    for one in 0..players.len() {
+                        let two = (one + random.gen_range(1..players.len())) % players.len();
+                        totals[one] += self.play(&players[one], &players[two]).0;
+                        games[one] += 1;
+                    }
+                }
+            }
+        }
+
+        (
+            average(&totals, &games),
+            average(&opponent_totals, &opponent_games),
+        )
+    }
+}
+
+fn average(totals: &[f32], games: &[usize]) -> Vec<f32> {
+    totals
+        .iter()
+        .zip(games.iter())
+        .map(|(total, games)| total / (*games).max(1) as f32)
+        .collect()
+}
@@ -0,0 +1,229 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rand::Rng;
+
+use crate::engines::cooperative_engine_params::{CooperativeEngineParams, TeamFn};
+use crate::engines::engine_context::EngineContext;
+use crate::engines::genetic_engine::GeneticEngine;
+use crate::engines::genome::genes::gene::Gene;
+use crate::engines::optimize::Optimize;
+use crate::engines::score::Score;
+
+// Decoded representatives of every population, indexed by member.
+pub type Board = Rc<RefCell<Vec<Vec<Rc<dyn Any>>>>>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Collaborator {
+    Best,
+    Random,
+    Both,
+}
+
+pub struct Team {
+    members: Vec<Rc<dyn Any>>,
+}
+
+impl Team {
+    pub fn new(members: Vec<Rc<dyn Any>>) -> Self {
+        Team { members }
+    }
+
+    pub fn get<T: 'static>(&self, index: usize) -> &T {
+        self.members[index].downcast_ref::<T>().unwrap_or_else(|| {
+            panic!(
+                "CooperativeEngine - team member {} does not decode to the requested type.",
+                index
+            )
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
+
+pub struct CooperativeContext {
+    pub contexts: Vec<Box<dyn Any>>,
+    pub index: i32,
+}
+
+impl CooperativeContext {
+    pub fn get<C: 'static>(&self, index: usize) -> &C {
+        self.contexts[index].downcast_ref::<C>().unwrap_or_else(|| {
+            panic!(
+                "CooperativeEngine - context {} is not of the requested type.",
+                index
+            )
+        })
+    }
+}
+
+// Lets engines over different genes and decoded types run side by side.
+pub trait Cooperate {
+    fn start(&self) -> Box<dyn Any>;
+    fn next(&self, ctx: &mut Box<dyn Any>);
+    fn stop(&self, ctx: &mut Box<dyn Any>) -> Box<dyn Any>;
+    fn optimize(&self) -> &Optimize;
+    fn collaborators(&self, ctx: &dyn Any, collaborator: Collaborator) -> Vec<Rc<dyn Any>>;
+}
+
+impl<'a, G, A, T> Cooperate for GeneticEngine<'a, G, A, T>
+where
+    G: Gene<G, A> + 'static,
+    A: 'static,
+    T: Clone + 'static,
+{
+    fn start(&self) -> Box<dyn Any> {
+        Box::new(GeneticEngine::start(self))
+    }
+
+    fn next(&self, ctx: &mut Box<dyn Any>) {
+        GeneticEngine::next(self, context(ctx.as_mut()));
+    }
+
+    fn stop(&self, ctx: &mut Box<dyn Any>) -> Box<dyn Any> {
+        Box::new(GeneticEngine::stop(self, context(ctx.as_mut())))
+    }
+
+    fn optimize(&self) -> &Optimize {
+        GeneticEngine::optimize(self)
+    }
+
+    fn collaborators(&self, ctx: &dyn Any, collaborator: Collaborator) -> Vec<Rc<dyn Any>> {
+        let ctx = ctx
+            .downcast_ref::<EngineContext<G, A, T>>()
+            .expect("CooperativeEngine - context does not belong to this engine.");
+        let codex = self.codex();
+        let random = rand::thread_rng().gen_range(0..ctx.population.len());
+        let indices = match collaborator {
+            Collaborator::Best => vec![0],
+            Collaborator::Random => vec![random],
+            Collaborator::Both => vec![0, random],
+        };
+
+        indices
+            .iter()
+            .map(|index| {
+                Rc::new(codex.decode(ctx.population.get(*index).genotype())) as Rc<dyn Any>
+            })
+            .collect()
+    }
+}
+
+pub struct CooperativeEngine<'a> {
+    pub members: Vec<Box<dyn Cooperate + 'a>>,
+    pub collaborator: Collaborator,
+    board: Board,
+}
+
+impl<'a> CooperativeEngine<'a> {
+    pub fn new(
+        members: Vec<Box<dyn Cooperate + 'a>>,
+        collaborator: Collaborator,
+        board: Board,
+    ) -> Self {
+        CooperativeEngine {
+            members,
+            collaborator,
+            board,
+        }
+    }
+
+    pub fn from_fn(fitness_fn: impl Fn(&Team) -> Score + 'static) -> CooperativeEngineParams<'a> {
+        CooperativeEngineParams::new(fitness_fn)
+    }
+
+    pub fn run<F>(&self, limit: F) -> CooperativeContext
+    where
+        F: Fn(&CooperativeContext) -> bool,
+    {
+        let mut ctx = CooperativeContext {
+            contexts: self.members.iter().map(|member| member.start()).collect(),
+            index: 0,
+        };
+
+        for (index, member) in self.members.iter().enumerate() {
+            let collaborators =
+                member.collaborators(ctx.contexts[index].as_ref(), Collaborator::Random);
+            self.board.borrow_mut()[index] = collaborators;
+        }
+
+        loop {
+            for (index, member) in self.members.iter().enumerate() {
+                member.next(&mut ctx.contexts[index]);
+
+                let collaborators =
+                    member.collaborators(ctx.contexts[index].as_ref(), self.collaborator);
+                self.board.borrow_mut()[index] = collaborators;
+            }
+
+            ctx.index += 1;
+
+            if limit(&ctx) {
+                break CooperativeContext {
+                    contexts: self
+                        .members
+                        .iter()
+                        .zip(ctx.contexts.iter_mut())
+                        .map(|(member, context)| member.stop(context))
+                        .collect(),
+                    index: ctx.index,
+                };
+            }
+        }
+    }
+}
+
+// Scores a member against the current representatives of every other population,
+// one team per representative slot, and keeps the best of them.
+pub fn team_score(
+    fitness_fn: &TeamFn,
+    board: &Board,
+    optimize: &Optimize,
+    index: usize,
+    member: Rc<dyn Any>,
+) -> Score {
+    let board = board.borrow();
+    let slots = board
+        .iter()
+        .enumerate()
+        .filter(|(other, _)| *other != index)
+        .map(|(_, representatives)| representatives.len())
+        .max()
+        .unwrap_or(1);
+
+    (0..slots)
+        .map(|slot| {
+            let members = board
+                .iter()
+                .enumerate()
+                .map(|(other, representatives)| match other == index {
+                    true => Rc::clone(&member),
+                    false => Rc::clone(&representatives[slot % representatives.len()]),
+                })
+                .collect();
+
+            fitness_fn(&Team::new(members))
+        })
+        .reduce(|best, score| match optimize.is_better(&score, &best) {
+            true => score,
+            false => best,
+        })
+        .expect("CooperativeEngine - no collaborators to evaluate with.")
+}
+
+fn context<G, A, T>(ctx: &mut dyn Any) -> &mut EngineContext<G, A, T>
+where
+    G: Gene<G, A> + 'static,
+    A: 'static,
+    T: 'static,
+{
+    ctx.downcast_mut::<EngineContext<G, A, T>>()
+        .expect("CooperativeEngine - context does not belong to this engine.")
+}
@@ -0,0 +1,109 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::engines::cooperative_engine::{
+    team_score, Board, Collaborator, Cooperate, CooperativeEngine, Team,
+};
+use crate::engines::genetic_engine_params::GeneticEngineParams;
+use crate::engines::genome::genes::gene::Gene;
+use crate::engines::optimize::Optimize;
+use crate::engines::score::Score;
+
+pub type TeamFn = Arc<dyn Fn(&Team) -> Score>;
+
+type MemberFn<'a> = Box<dyn FnOnce(&TeamFn, &Board, Optimize) -> Box<dyn Cooperate + 'a> + 'a>;
+
+pub struct CooperativeEngineParams<'a> {
+    pub members: Vec<MemberFn<'a>>,
+    pub fitness_fn: TeamFn,
+    pub collaborator: Collaborator,
+    pub optimize: Optimize,
+}
+
+impl<'a> CooperativeEngineParams<'a> {
+    pub fn new(fitness_fn: impl Fn(&Team) -> Score + 'static) -> Self {
+        CooperativeEngineParams {
+            members: Vec::new(),
+            fitness_fn: Arc::new(fitness_fn),
+            collaborator: Collaborator::Best,
+            optimize: Optimize::Maximize,
+        }
+    }
+
+    pub fn member<G, A, T>(mut self, params: GeneticEngineParams<'a, G, A, T>) -> Self
+    where
+        G: Gene<G, A> + 'static,
+        A: 'static,
+        T: Clone + 'static,
+    {
+        let index = self.members.len();
+        self.members
+            .push(Box::new(move |fitness_fn, board, optimize| {
+                let fitness_fn = Arc::clone(fitness_fn);
+                let board = Rc::clone(board);
+                let mut params = params.fitness_fn(move |member: T| {
+                    team_score(
+                        &fitness_fn,
+                        &board,
+                        &optimize,
+                        index,
+                        Rc::new(member) as Rc<dyn Any>,
+                    )
+                });
+
+                params.optimize = optimize;
+                Box::new(rescored(params).build())
+            }));
+        self
+    }
+
+    pub fn collaborator(mut self, collaborator: Collaborator) -> Self {
+        self.collaborator = collaborator;
+        self
+    }
+
+    pub fn minimizing(mut self) -> Self {
+        self.optimize = Optimize::Minimize;
+        self
+    }
+
+    pub fn maximizing(mut self) -> Self {
+        self.optimize = Optimize::Maximize;
+        self
+    }
+
+    pub fn build(self) -> CooperativeEngine<'a> {
+        if self.members.len() < 2 {
+            panic!("CooperativeEngine - at least two members are required.");
+        }
+
+        let board: Board = Rc::new(RefCell::new(vec![Vec::new(); self.members.len()]));
+        let members = self
+            .members
+            .into_iter()
+            .map(|member| member(&self.fitness_fn, &board, self.optimize))
+            .collect();
+
+        CooperativeEngine::new(members, self.collaborator, board)
+    }
+}
+
+// Collaborators change every generation, so scores from earlier generations are stale.
+fn rescored<'a, G, A, T>(
+    params: GeneticEngineParams<'a, G, A, T>,
+) -> GeneticEngineParams<'a, G, A, T>
+where
+    G: Gene<G, A>,
+    T: Clone,
+{
+    let refresh_fn = params.refresh_fn.clone();
+    params.refresh_fn(move |index| {
+        if let Some(refresh_fn) = &refresh_fn {
+            refresh_fn(index);
+        }
+
+        true
+    })
+}
@@ -1,5 +1,7 @@
 use crate::engines::genome::genes::gene::Gene;
+use crate::engines::genome::genotype::Genotype;
 use crate::engines::genome::population::Population;
+use crate::engines::genome::species::Species;
 use crate::engines::schema::timer::Timer;
 
 use super::score::Score;
@@ -13,6 +15,11 @@ where
     pub index: i32,
     pub timer: Timer,
     pub score: Option<Score>,
+    pub species: Vec<Species<G, A>>,
+    pub species_threshold: f32,
+    pub next_species_id: usize,
+    pub opponents: Population<G, A>,
+    pub hall_of_fame: Vec<Genotype<G, A>>,
 }
 
 impl<G, A, T> EngineContext<G, A, T>
@@ -40,6 +47,11 @@ where
             index: self.index,
             timer: self.timer.clone(),
             score: self.score.clone(),
+            species: self.species.clone(),
+            species_threshold: self.species_threshold,
+            next_species_id: self.next_species_id,
+            opponents: self.opponents.clone(),
+            hall_of_fame: self.hall_of_fame.clone(),
         }
     }
 }
@@ -54,6 +66,7 @@ where
         write!(f, "  score: {:?},\n", self.score())?;
         write!(f, "  index: {:?},\n", self.index)?;
         write!(f, "  size: {:?},\n", self.population.len())?;
+        writeln!(f, "  species: {:?},", self.species.len())?;
         write!(f, "  duration: {:?},\n", self.timer.elapsed())?;
         write!(f, "}}")
     }
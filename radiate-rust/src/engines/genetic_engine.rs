@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
 use crate::engines::alterers::alter::Alter;
+use crate::engines::competition::{Competition, Matchup};
 use crate::engines::genetic_engine_params::GeneticEngineParams;
 use crate::engines::genome::genes::gene::Gene;
+use crate::engines::genome::genotype::Genotype;
 use crate::engines::genome::population::Population;
+use crate::engines::genome::species::{Speciation, Species};
 use crate::engines::optimize::Optimize;
 use crate::engines::schema::timer::Timer;
 use crate::engines::score::Score;
@@ -41,28 +44,66 @@ where
         let mut ctx = self.start();
 
         loop {
-            self.evaluate(&mut ctx);
+            self.next(&mut ctx);
 
-            let mut survivors = self.select_survivors(&ctx.population);
-            let mut offspring = self.select_offspring(&ctx.population);
+            if limit(&ctx) {
+                break self.stop(&mut ctx);
+            }
+        }
+    }
 
-            self.alter(&mut offspring, ctx.index);
+    pub fn next(&self, ctx: &mut EngineContext<G, A, T>) {
+        self.refresh(ctx);
+        self.evaluate(ctx);
+        self.speciate(ctx);
 
-            self.filter(&mut survivors, ctx.index);
-            self.filter(&mut offspring, ctx.index);
+        let (mut survivors, mut offspring) = match self.speciation() {
+            Some(_) => self.breed_species(ctx),
+            None => {
+                let survivors = self.select_survivors(&ctx.population);
+                let mut offspring = self.select_offspring(&ctx.population);
 
-            self.recombine(&mut ctx, survivors, offspring);
+                self.alter(&mut offspring, ctx.index);
 
-            self.evaluate(&mut ctx);
-            self.audit(&mut ctx);
+                (survivors, offspring)
+            }
+        };
 
-            if limit(&ctx) {
-                break self.stop(&mut ctx);
+        self.filter(&mut survivors, ctx.index);
+        self.filter(&mut offspring, ctx.index);
+        self.learn(&mut offspring);
+
+        self.recombine(ctx, survivors, offspring);
+        self.breed_opponents(ctx);
+
+        self.evaluate(ctx);
+        self.audit(ctx);
+
+        // Species members index the population, which recombine and audit have rebuilt.
+        // Species left empty keep their allotment until the next generation prunes them.
+        if let Some(speciation) = self.speciation() {
+            Self::assign_species(ctx, speciation);
+        }
+    }
+
+    fn refresh(&self, handle: &mut EngineContext<G, A, T>) {
+        let refreshed = match &self.params.refresh_fn {
+            Some(refresh_fn) => refresh_fn(handle.index),
+            None => false,
+        };
+
+        if refreshed {
+            for individual in handle.population.iter_mut() {
+                individual.set_score(None);
             }
         }
     }
 
     fn evaluate(&self, handle: &mut EngineContext<G, A, T>) {
+        if let Some(competition) = self.competition() {
+            return self.compete(handle, competition);
+        }
+
         let codex = self.codex();
         let fitness_fn = self.fitness_fn();
         let optimize = self.optimize();
@@ -80,6 +121,82 @@ where
         optimize.sort(&mut handle.population);
     }
 
+    fn compete(&self, handle: &mut EngineContext<G, A, T>, competition: &Competition<T>) {
+        let unscored = handle
+            .population
+            .iter()
+            .chain(handle.opponents.iter())
+            .any(|individual| individual.score().is_none());
+        if !unscored {
+            return;
+        }
+
+        let codex = self.codex();
+        let optimize = self.optimize();
+
+        let players = handle
+            .population
+            .iter()
+            .map(|individual| codex.decode(individual.genotype()))
+            .collect::<Vec<T>>();
+        let opponents = match competition.matchup {
+            Matchup::HallOfFame(_) => handle
+                .hall_of_fame
+                .iter()
+                .map(|genotype| codex.decode(genotype))
+                .collect::<Vec<T>>(),
+            _ => handle
+                .opponents
+                .iter()
+                .map(|individual| codex.decode(individual.genotype()))
+                .collect::<Vec<T>>(),
+        };
+
+        let (scores, opponent_scores) = competition.compete(&players, &opponents);
+        for (individual, score) in handle.population.iter_mut().zip(scores) {
+            individual.set_score(Some(Score::from_f32(score)));
+        }
+
+        if competition.has_opponents() {
+            for (individual, score) in handle.opponents.iter_mut().zip(opponent_scores) {
+                individual.set_score(Some(Score::from_f32(score)));
+            }
+            optimize.sort(&mut handle.opponents);
+        }
+
+        optimize.sort(&mut handle.population);
+
+        if let Matchup::HallOfFame(_) = competition.matchup {
+            handle
+                .hall_of_fame
+                .push(handle.population.get(0).genotype().clone());
+            if handle.hall_of_fame.len() > competition.hall_of_fame_size {
+                handle.hall_of_fame.remove(0);
+            }
+        }
+    }
+
+    fn breed_opponents(&self, handle: &mut EngineContext<G, A, T>) {
+        if !self
+            .competition()
+            .is_some_and(|competition| competition.has_opponents())
+        {
+            return;
+        }
+
+        let mut survivors = self.select_survivors(&handle.opponents);
+        let mut offspring = self.select_offspring(&handle.opponents);
+
+        self.alter(&mut offspring, handle.index);
+        self.filter(&mut survivors, handle.index);
+        self.filter(&mut offspring, handle.index);
+
+        handle.opponents = survivors
+            .into_iter()
+            .chain(offspring)
+            .collect::<Population<G, A>>();
+    }
+
     fn select_survivors(&self, population: &Population<G, A>) -> Population<G, A> {
         let selector = self.survivor_selector();
         let count = self.survivor_count();
@@ -96,6 +213,234 @@ where
         selector.select(population, optimize, count)
     }
 
+    fn speciate(&self, handle: &mut EngineContext<G, A, T>) {
+        let speciation = match self.speciation() {
+            Some(speciation) => speciation,
+            None => return,
+        };
+        let optimize = self.optimize();
+        let threshold = handle.species_threshold;
+
+        Self::assign_species(handle, speciation);
+        handle.species.retain(|species| !species.is_empty());
+
+        let mut champion = None;
+        for species in handle.species.iter_mut() {
+            let leader_index = Self::leader(&handle.population, &species.members, optimize);
+            let leader = handle.population.get(leader_index);
+            let leader_score = leader.score().clone();
+            champion = match (champion, leader_score.as_ref()) {
+                (Some((_, best)), Some(score)) if optimize.is_better(score, &best) => {
+                    Some((species.id, score.clone()))
+                }
+                (None, Some(score)) => Some((species.id, score.clone())),
+                (champion, _) => champion,
+            };
+
+            species.representative = leader.genotype().clone();
+            species.mean_score = species
+                .members
+                .iter()
+                .map(|idx| Self::fitness(handle.population.get(*idx).score()))
+                .sum::<f32>()
+                / species.len() as f32;
+
+            let improved = match (&leader_score, &species.best_score) {
+                (Some(current), Some(best)) => optimize.is_better(current, best),
+                (Some(_), None) => true,
+                _ => false,
+            };
+
+            if improved {
+                species.best_score = leader_score;
+                species.stagnation = 0;
+            } else {
+                species.stagnation += 1;
+            }
+        }
+
+        let champion = champion.map(|(id, _)| id);
+        handle.species.retain(|species| {
+            species.stagnation <= speciation.max_stagnation || Some(species.id) == champion
+        });
+        handle.species_threshold = speciation.adjust_threshold(threshold, handle.species.len());
+
+        self.share_fitness(handle);
+    }
+
+    fn assign_species(handle: &mut EngineContext<G, A, T>, speciation: &Speciation<G, A>) {
+        let threshold = handle.species_threshold;
+
+        for species in handle.species.iter_mut() {
+            species.members.clear();
+            species.shared_fitness.clear();
+        }
+
+        for idx in 0..handle.population.len() {
+            let genotype = handle.population.get(idx).genotype();
+            let existing = handle
+                .species
+                .iter_mut()
+                .find(|species| speciation.distance(&species.representative, genotype) < threshold);
+
+            match existing {
+                Some(species) => species.members.push(idx),
+                None => {
+                    let mut species =
+                        Species::new(handle.next_species_id, genotype.clone(), handle.index);
+                    species.members.push(idx);
+                    handle.next_species_id += 1;
+                    handle.species.push(species);
+                }
+            }
+        }
+    }
+
+    fn leader(population: &Population<G, A>, members: &[usize], optimize: &Optimize) -> usize {
+        members
+            .iter()
+            .cloned()
+            .reduce(
+                |best, idx| match (population.get(idx).score(), population.get(best).score()) {
+                    (Some(score), Some(best_score)) if optimize.is_better(score, best_score) => idx,
+                    (Some(_), None) => idx,
+                    _ => best,
+                },
+            )
+            .unwrap()
+    }
+
+    fn share_fitness(&self, handle: &mut EngineContext<G, A, T>) {
+        let optimize = self.optimize();
+        let scores = handle
+            .species
+            .iter()
+            .flat_map(|species| species.members.iter())
+            .map(|idx| Self::fitness(handle.population.get(*idx).score()))
+            .collect::<Vec<f32>>();
+
+        let min = scores.iter().cloned().fold(f32::INFINITY, f32::min);
+        let max = scores.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+
+        for species in handle.species.iter_mut() {
+            let size = species.len() as f32;
+            species.shared_fitness = species
+                .members
+                .iter()
+                .map(|idx| {
+                    let score = Self::fitness(handle.population.get(*idx).score());
+                    let shifted = match optimize {
+                        Optimize::Maximize => score - min,
+                        Optimize::Minimize => max - score,
+                    };
+
+                    shifted / size
+                })
+                .collect();
+            species.adjusted_fitness = species.shared_fitness.iter().sum::<f32>();
+        }
+
+        let count = self.offspring_count();
+        let total = handle
+            .species
+            .iter()
+            .map(|species| species.adjusted_fitness)
+            .sum::<f32>();
+
+        let shares = handle
+            .species
+            .iter()
+            .map(|species| match total > 0.0 {
+                true => count as f32 * species.adjusted_fitness / total,
+                false => count as f32 / handle.species.len() as f32,
+            })
+            .collect::<Vec<f32>>();
+
+        let mut allotted = 0;
+        for (species, share) in handle.species.iter_mut().zip(shares.iter()) {
+            species.offspring = share.floor() as usize;
+            allotted += species.offspring;
+        }
+
+        let mut remainders = (0..shares.len()).collect::<Vec<usize>>();
+        remainders.sort_by(|a, b| {
+            (shares[*b] - shares[*b].floor())
+                .partial_cmp(&(shares[*a] - shares[*a].floor()))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        for idx in remainders
+            .into_iter()
+            .cycle()
+            .take(count.saturating_sub(allotted))
+        {
+            handle.species[idx].offspring += 1;
+        }
+    }
+
+    fn breed_species(
+        &self,
+        handle: &EngineContext<G, A, T>,
+    ) -> (Population<G, A>, Population<G, A>) {
+        let selector = self.offspring_selector();
+        let optimize = self.optimize();
+
+        let mut survivors = handle
+            .species
+            .iter()
+            .flat_map(|species| species.members.iter())
+            .map(|idx| handle.population.get(*idx).clone())
+            .collect::<Population<G, A>>();
+
+        let mut offspring = Vec::with_capacity(self.offspring_count());
+        for species in handle
+            .species
+            .iter()
+            .filter(|species| species.offspring > 0)
+        {
+            // Parents are chosen on shared fitness, where higher is always better.
+            let mut members = species
+                .members
+                .iter()
+                .zip(species.shared_fitness.iter())
+                .map(|(idx, shared)| {
+                    let mut member = handle.population.get(*idx).clone();
+                    member.set_score(Some(Score::from_f32(*shared)));
+                    member
+                })
+                .collect::<Population<G, A>>();
+            Optimize::Maximize.sort(&mut members);
+
+            let mut children = selector.select(&members, &Optimize::Maximize, species.offspring);
+            for child in children.iter_mut() {
+                let score = species
+                    .members
+                    .iter()
+                    .map(|idx| handle.population.get(*idx))
+                    .find(|member| member.genotype() == child.genotype())
+                    .and_then(|member| member.score().clone());
+                child.set_score(score);
+            }
+
+            self.alter(&mut children, handle.index);
+            offspring.extend(children);
+        }
+
+        optimize.sort(&mut survivors);
+
+        (
+            self.select_survivors(&survivors),
+            Population::from_vec(offspring),
+        )
+    }
+
+    fn fitness(score: &Option<Score>) -> f32 {
+        match score {
+            Some(score) => score.as_float(),
+            None => 0.0,
+        }
+    }
+
     fn alter(&self, population: &mut Population<G, A>, generation: i32) {
         let alterer = self.alterer();
         let optimize = self.optimize();
@@ -106,16 +451,56 @@ where
     fn filter(&self, population: &mut Population<G, A>, generation: i32) {
         let max_age = self.params.max_age;
         let codex = self.codex();
+        let has_repair_fn = self.params.repair_fn.is_some();
 
         for i in 0..population.len() {
             let phenotype = population.get(i);
 
             if phenotype.age(generation) > max_age {
                 population.set(i, Phenotype::from_genotype(codex.encode(), generation));
-            } else if !phenotype.genotype().is_valid() {
-                population.set(i, Phenotype::from_genotype(codex.encode(), generation));
+            } else if !phenotype.genotype().is_valid()
+                || (has_repair_fn && phenotype.score().is_none())
+            {
+                let mut genotype = phenotype.genotype().clone();
+                let born = phenotype.generation;
+
+                if self.repair(&mut genotype) {
+                    population.set(i, Phenotype::from_genotype(genotype, born));
+                } else {
+                    population.set(i, Phenotype::from_genotype(codex.encode(), generation));
+                }
+            }
+        }
+    }
+
+    fn learn(&self, population: &mut Population<G, A>) {
+        if let Some(lamarck_fn) = &self.params.lamarck_fn {
+            for phenotype in population.iter_mut() {
+                lamarck_fn(phenotype.genotype_mut());
+                phenotype.set_score(None);
+            }
+        }
+    }
+
+    fn repair(&self, genotype: &mut Genotype<G, A>) -> bool {
+        let repair = &self.params.repair;
+
+        for chromosome in genotype.iter_mut() {
+            for gene in chromosome.iter_mut() {
+                if !gene.is_valid() {
+                    if let Some(repaired) = gene.repair(repair) {
+                        *gene = repaired;
+                    }
+                }
             }
         }
+
+        let accepted = match &self.params.repair_fn {
+            Some(repair_fn) => repair_fn(genotype),
+            None => true,
+        };
+
+        accepted && genotype.is_valid()
     }
 
     fn recombine(
@@ -131,28 +516,41 @@ where
     }
 
     fn audit(&self, output: &mut EngineContext<G, A, T>) {
-        let codex = self.codex();
         let optimize = self.optimize();
 
         if !output.population.is_sorted {
             self.optimize().sort(&mut output.population);
         }
 
-        if let Some(current_score) = &output.score {
+        // Scores from earlier generations are not comparable once the population has
+        // been re-scored against a new opponent set, batch or collaborator.
+        if self.competition().is_some() || self.params.refresh_fn.is_some() {
+            output.score = output.population.get(0).score().clone();
+            output.best = self.decode_best(output.population.get(0).genotype());
+        } else if let Some(current_score) = &output.score {
             if let Some(best_score) = output.population.get(0).score() {
                 if optimize.is_better(best_score, &current_score) {
                     output.score = Some(best_score.clone());
-                    output.best = codex.decode(&output.population.get(0).genotype());
+                    output.best = self.decode_best(output.population.get(0).genotype());
                 }
             }
         } else {
             output.score = output.population.get(0).score().clone();
-            output.best = codex.decode(&output.population.get(0).genotype());
+            output.best = self.decode_best(output.population.get(0).genotype());
         }
 
         output.index += 1;
     }
 
+    fn decode_best(&self, genotype: &Genotype<G, A>) -> T {
+        let decoded = self.codex().decode(genotype);
+
+        match &self.params.best_fn {
+            Some(best_fn) => best_fn(decoded),
+            None => decoded,
+        }
+    }
+
     fn survivor_selector(&self) -> &impl Select<G, A> {
         &self.params.survivor_selector
     }
@@ -165,7 +563,7 @@ where
         self.params.alterer.as_ref().unwrap()
     }
 
-    fn codex(&self) -> &Arc<&'a dyn Codex<G, A, T>> {
+    pub fn codex(&self) -> &Arc<&'a dyn Codex<G, A, T>> {
         self.params.codex.as_ref().unwrap()
     }
 
@@ -177,10 +575,18 @@ where
         self.params.population.as_ref().unwrap()
     }
 
-    fn optimize(&self) -> &Optimize {
+    pub fn optimize(&self) -> &Optimize {
         &self.params.optimize
     }
 
+    fn speciation(&self) -> Option<&Speciation<G, A>> {
+        self.params.speciation.as_ref()
+    }
+
+    fn competition(&self) -> Option<&Competition<T>> {
+        self.params.competition.as_ref()
+    }
+
     fn survivor_count(&self) -> usize {
         self.params.population_size - self.offspring_count()
     }
@@ -189,19 +595,36 @@ where
         (self.params.population_size as f32 * self.params.offspring_fraction) as usize
     }
 
-    fn start(&self) -> EngineContext<G, A, T> {
+    pub fn start(&self) -> EngineContext<G, A, T> {
         let population = self.population();
+        let opponents = match self.competition() {
+            Some(competition) if competition.has_opponents() => {
+                let codex = self.codex();
+                Population::from_fn(population.len(), || {
+                    Phenotype::from_genotype(codex.encode(), 0)
+                })
+            }
+            _ => Population::from_vec(Vec::new()),
+        };
 
         EngineContext {
             population: population.clone(),
-            best: self.codex().decode(&population.get(0).genotype()),
+            best: self.decode_best(population.get(0).genotype()),
             index: 0,
             timer: Timer::new(),
             score: None,
+            species: Vec::new(),
+            species_threshold: self
+                .speciation()
+                .map(|speciation| speciation.threshold)
+                .unwrap_or(0.0),
+            next_species_id: 0,
+            opponents,
+            hall_of_fame: Vec::new(),
         }
     }
 
-    fn stop(&self, output: &mut EngineContext<G, A, T>) -> EngineContext<G, A, T> {
+    pub fn stop(&self, output: &mut EngineContext<G, A, T>) -> EngineContext<G, A, T> {
         output.timer.stop();
         output.clone()
     }
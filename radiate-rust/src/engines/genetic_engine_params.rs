@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
 use crate::engines::alterers::composite_alterer::CompositeAlterer;
+use crate::engines::competition::Competition;
 use crate::engines::genetic_engine::GeneticEngine;
-use crate::engines::genome::genes::gene::Gene;
+use crate::engines::genome::genes::gene::{BoundRepair, Gene};
+use crate::engines::genome::genotype::Genotype;
 use crate::engines::genome::phenotype::Phenotype;
 use crate::engines::genome::population::Population;
+use crate::engines::genome::species::Speciation;
 use crate::engines::optimize::Optimize;
 use crate::engines::score::Score;
 use crate::engines::selectors::selector::Selector;
@@ -12,6 +15,9 @@ use crate::engines::selectors::selector::Selector;
 use super::alterers::alter::Alterer;
 use super::codexes::Codex;
 
+pub type RepairFn<G, A> = Arc<dyn Fn(&mut Genotype<G, A>) -> bool>;
+pub type LamarckFn<G, A> = Arc<dyn Fn(&mut Genotype<G, A>)>;
+
 pub struct GeneticEngineParams<'a, G, A, T>
 where
     G: Gene<G, A>,
@@ -20,6 +26,7 @@ where
     pub population_size: usize,
     pub max_age: i32,
     pub offspring_fraction: f32,
+    pub repair: BoundRepair,
     pub optimize: Optimize,
     pub survivor_selector: Selector,
     pub offspring_selector: Selector,
@@ -27,6 +34,12 @@ where
     pub population: Option<Population<G, A>>,
     pub codex: Option<Arc<&'a dyn Codex<G, A, T>>>,
     pub fitness_fn: Option<Arc<dyn Fn(T) -> Score>>,
+    pub best_fn: Option<Arc<dyn Fn(T) -> T>>,
+    pub refresh_fn: Option<Arc<dyn Fn(i32) -> bool>>,
+    pub repair_fn: Option<RepairFn<G, A>>,
+    pub lamarck_fn: Option<LamarckFn<G, A>>,
+    pub speciation: Option<Speciation<G, A>>,
+    pub competition: Option<Competition<T>>,
 }
 
 impl<'a, G, A, T> GeneticEngineParams<'a, G, A, T>
@@ -39,6 +52,7 @@ where
             population_size: 100,
             max_age: 25,
             offspring_fraction: 0.8,
+            repair: BoundRepair::Discard,
             optimize: Optimize::Maximize,
             survivor_selector: Selector::Tournament(3),
             offspring_selector: Selector::Roulette,
@@ -46,6 +60,12 @@ where
             codex: None,
             population: None,
             fitness_fn: None,
+            best_fn: None,
+            refresh_fn: None,
+            repair_fn: None,
+            lamarck_fn: None,
+            speciation: None,
+            competition: None,
         }
     }
 
@@ -79,6 +99,31 @@ where
         self
     }
 
+    pub fn best_fn(mut self, best_func: impl Fn(T) -> T + 'static) -> Self {
+        self.best_fn = Some(Arc::new(best_func));
+        self
+    }
+
+    pub fn refresh_fn(mut self, refresh_func: impl Fn(i32) -> bool + 'static) -> Self {
+        self.refresh_fn = Some(Arc::new(refresh_func));
+        self
+    }
+
+    pub fn repair(mut self, repair: BoundRepair) -> Self {
+        self.repair = repair;
+        self
+    }
+
+    pub fn repair_fn(mut self, repair_fn: impl Fn(&mut Genotype<G, A>) -> bool + 'static) -> Self {
+        self.repair_fn = Some(Arc::new(repair_fn));
+        self
+    }
+
+    pub fn lamarck_fn(mut self, lamarck_fn: impl Fn(&mut Genotype<G, A>) + 'static) -> Self {
+        self.lamarck_fn = Some(Arc::new(lamarck_fn));
+        self
+    }
+
     pub fn survivor_selector(mut self, selector: Selector) -> Self {
         self.survivor_selector = selector;
         self
@@ -94,6 +139,16 @@ where
         self
     }
 
+    pub fn speciation(mut self, speciation: Speciation<G, A>) -> Self {
+        self.speciation = Some(speciation);
+        self
+    }
+
+    pub fn competition(mut self, competition: Competition<T>) -> Self {
+        self.competition = Some(competition);
+        self
+    }
+
     pub fn minimizing(mut self) -> Self {
         self.optimize = Optimize::Minimize;
         self
@@ -112,7 +167,7 @@ where
             panic!("Codex not set");
         }
 
-        if !self.fitness_fn.is_some() {
+        if self.fitness_fn.is_none() && self.competition.is_none() {
             panic!("Fitness function not set");
         }
 
@@ -5,6 +5,9 @@ where
     G: Gene<G, A>,
 {
     pub genes: Vec<G>,
+    pub min_length: usize,
+    pub max_length: usize,
+    template: Option<G>,
     _allele: std::marker::PhantomData<A>,
 }
 
@@ -14,11 +17,29 @@ where
 {
     pub fn from_genes(genes: Vec<G>) -> Self {
         Chromosome {
+            template: genes.first().cloned(),
             genes,
+            min_length: 0,
+            max_length: usize::MAX,
             _allele: std::marker::PhantomData,
         }
     }
 
+    pub fn with_length_bounds(mut self, min_length: usize, max_length: usize) -> Self {
+        self.min_length = min_length;
+        self.max_length = max_length;
+        self
+    }
+
+    pub fn with_template(mut self, template: G) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    pub fn template(&self) -> Option<&G> {
+        self.genes.first().or(self.template.as_ref())
+    }
+
     pub fn get_gene(&self, index: usize) -> &G {
         &self.genes[index]
     }
@@ -31,12 +52,37 @@ where
         &self.genes
     }
 
+    pub fn set_genes(&mut self, genes: Vec<G>) {
+        if let Some(gene) = genes.first().or(self.genes.first()) {
+            self.template = Some(gene.clone());
+        }
+
+        self.genes = genes;
+    }
+
+    pub fn insert_genes(&mut self, index: usize, genes: Vec<G>) {
+        self.genes.splice(index..index, genes);
+    }
+
+    pub fn remove_genes(&mut self, start: usize, end: usize) -> Vec<G> {
+        let removed = self.genes.drain(start..end).collect::<Vec<G>>();
+        if let Some(gene) = self.genes.first().or(removed.first()) {
+            self.template = Some(gene.clone());
+        }
+
+        removed
+    }
+
+    pub fn is_length_valid(&self, length: usize) -> bool {
+        length >= self.min_length && length <= self.max_length
+    }
+
     pub fn len(&self) -> usize {
         self.genes.len()
     }
 
     pub fn is_valid(&self) -> bool {
-        self.genes.iter().all(|gene| gene.is_valid())
+        self.is_length_valid(self.len()) && self.genes.iter().all(|gene| gene.is_valid())
     }
 
     pub fn iter(&self) -> std::slice::Iter<G> {
@@ -55,6 +101,9 @@ where
     fn clone(&self) -> Self {
         Chromosome {
             genes: self.genes.clone(),
+            min_length: self.min_length,
+            max_length: self.max_length,
+            template: self.template.clone(),
             _allele: std::marker::PhantomData,
         }
     }
@@ -65,6 +114,10 @@ where
     G: Gene<G, A>,
 {
     fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
         for (a, b) in self.genes.iter().zip(other.genes.iter()) {
             if a != b {
                 return false;
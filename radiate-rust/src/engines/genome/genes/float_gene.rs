@@ -1,6 +1,6 @@
 use rand::random;
 
-use super::gene::{BoundGene, Gene, NumericGene, Valid};
+use super::gene::{BoundGene, BoundRepair, Gene, NumericGene, Valid};
 
 pub struct FloatGene {
     pub allele: f32,
@@ -20,6 +20,22 @@ impl FloatGene {
             lower_bound: f32::MIN,
         }
     }
+
+    pub fn clamp(&self, allele: f32) -> FloatGene {
+        let (lower, upper) = self.native_limits();
+        self.from_allele(&allele.max(lower).min(upper))
+    }
+
+    fn native_limits(&self) -> (f32, f32) {
+        let lower = self.min.max(self.lower_bound);
+        let upper = self.max.min(self.upper_bound);
+
+        if lower <= upper {
+            (lower, upper)
+        } else {
+            (self.min, self.max)
+        }
+    }
 }
 
 impl Valid for FloatGene {
@@ -52,6 +68,10 @@ impl Gene<FloatGene, f32> for FloatGene {
             lower_bound: self.lower_bound,
         }
     }
+
+    fn repair(&self, repair: &BoundRepair) -> Option<FloatGene> {
+        repair.apply(self)
+    }
 }
 
 impl BoundGene<FloatGene, f32> for FloatGene {
@@ -112,6 +132,23 @@ impl NumericGene<FloatGene, f32> for FloatGene {
             ..*self
         }
     }
+
+    fn as_f64(&self) -> f64 {
+        self.allele as f64
+    }
+
+    fn with_f64(&self, value: f64) -> FloatGene {
+        self.from_allele(&(value as f32))
+    }
+
+    fn creep(&self, step: i32) -> FloatGene {
+        self.clamp(self.allele + step as f32)
+    }
+
+    fn limits(&self) -> (f64, f64) {
+        let (lower, upper) = self.native_limits();
+        (lower as f64, upper as f64)
+    }
 }
 
 impl Clone for FloatGene {
@@ -4,6 +4,52 @@ pub trait Valid {
     }
 }
 
+pub enum BoundRepair {
+    Clamp,
+    Reflect,
+    Wrap,
+    Resample,
+    Discard,
+}
+
+impl BoundRepair {
+    pub fn apply<G, A>(&self, gene: &G) -> Option<G>
+    where
+        G: NumericGene<G, A>,
+    {
+        let (lower, upper) = gene.limits();
+        let range = upper - lower;
+        let value = gene.as_f64();
+
+        match self {
+            BoundRepair::Clamp => Some(gene.with_f64(value.max(lower).min(upper))),
+            BoundRepair::Reflect => {
+                if range <= 0_f64 || !range.is_finite() {
+                    return Some(gene.with_f64(value.max(lower).min(upper)));
+                }
+
+                let offset = (value - lower).rem_euclid(2_f64 * range);
+                let offset = if offset > range {
+                    2_f64 * range - offset
+                } else {
+                    offset
+                };
+
+                Some(gene.with_f64(lower + offset))
+            }
+            BoundRepair::Wrap => {
+                if range <= 0_f64 || !range.is_finite() {
+                    return Some(gene.with_f64(value.max(lower).min(upper)));
+                }
+
+                Some(gene.with_f64(lower + (value - lower).rem_euclid(range)))
+            }
+            BoundRepair::Resample => Some(gene.new_instance()),
+            BoundRepair::Discard => None,
+        }
+    }
+}
+
 pub trait Gene<G, A>: Clone + PartialEq + Valid
 where
     G: Gene<G, A>,
@@ -11,6 +57,13 @@ where
     fn allele(&self) -> &A;
     fn new_instance(&self) -> G;
     fn from_allele(&self, allele: &A) -> G;
+
+    fn repair(&self, repair: &BoundRepair) -> Option<G> {
+        match repair {
+            BoundRepair::Resample => Some(self.new_instance()),
+            _ => None,
+        }
+    }
 }
 
 pub trait BoundGene<G, A>: Gene<G, A>
@@ -31,4 +84,9 @@ where
     fn mul(&self, other: &G) -> G;
     fn div(&self, other: &G) -> G;
     fn mean(&self, other: &G) -> G;
+
+    fn as_f64(&self) -> f64;
+    fn with_f64(&self, value: f64) -> G;
+    fn creep(&self, step: i32) -> G;
+    fn limits(&self) -> (f64, f64);
 }
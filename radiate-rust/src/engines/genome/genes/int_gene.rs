@@ -1,7 +1,7 @@
 use rand::Rng;
 
 use super::{
-    gene::{BoundGene, Gene, NumericGene, Valid},
+    gene::{BoundGene, BoundRepair, Gene, NumericGene, Valid},
     Integer,
 };
 
@@ -26,6 +26,22 @@ impl<T: Integer<T>> IntGene<T> {
             lower_bound: T::MIN,
         }
     }
+
+    fn native_limits(&self) -> (T, T) {
+        let lower = match self.min > self.lower_bound {
+            true => self.min,
+            false => self.lower_bound,
+        };
+        let upper = match self.max < self.upper_bound {
+            true => self.max,
+            false => self.upper_bound,
+        };
+
+        match lower <= upper {
+            true => (lower, upper),
+            false => (self.min, self.max),
+        }
+    }
 }
 
 impl<T: Integer<T>> Gene<IntGene<T>, T> for IntGene<T> {
@@ -53,6 +69,10 @@ impl<T: Integer<T>> Gene<IntGene<T>, T> for IntGene<T> {
             lower_bound: self.lower_bound,
         }
     }
+
+    fn repair(&self, repair: &BoundRepair) -> Option<IntGene<T>> {
+        repair.apply(self)
+    }
 }
 
 impl<T: Integer<T>> Valid for IntGene<T> {
@@ -119,6 +139,34 @@ impl<T: Integer<T>> NumericGene<IntGene<T>, T> for IntGene<T> {
             ..*self
         }
     }
+
+    fn as_f64(&self) -> f64 {
+        self.allele.to_f64()
+    }
+
+    fn with_f64(&self, value: f64) -> IntGene<T> {
+        self.from_allele(&T::from_f64(value.round()))
+    }
+
+    fn creep(&self, step: i32) -> IntGene<T> {
+        let (lower, upper) = self.native_limits();
+        let delta = T::from_f64((step as f64).abs());
+        let allele = match step >= 0 {
+            true => self.allele.saturating_add(delta),
+            false => self.allele.saturating_sub(delta),
+        };
+
+        match allele {
+            allele if allele < lower => self.from_allele(&lower),
+            allele if allele > upper => self.from_allele(&upper),
+            allele => self.from_allele(&allele),
+        }
+    }
+
+    fn limits(&self) -> (f64, f64) {
+        let (lower, upper) = self.native_limits();
+        (lower.to_f64(), upper.to_f64())
+    }
 }
 
 impl<T: Integer<T>> Clone for IntGene<T> {
@@ -36,6 +36,10 @@ pub trait Integer<T>:
     const MAX: T;
 
     fn from_i32(value: i32) -> T;
+    fn from_f64(value: f64) -> T;
+    fn to_f64(&self) -> f64;
+    fn saturating_add(&self, other: T) -> T;
+    fn saturating_sub(&self, other: T) -> T;
 }
 
 // Implement Integer for i8, i16, i32, i64, and i128
@@ -46,6 +50,22 @@ impl Integer<i8> for i8 {
     fn from_i32(value: i32) -> i8 {
         value as i8
     }
+
+    fn from_f64(value: f64) -> i8 {
+        value as i8
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+
+    fn saturating_add(&self, other: i8) -> i8 {
+        i8::saturating_add(*self, other)
+    }
+
+    fn saturating_sub(&self, other: i8) -> i8 {
+        i8::saturating_sub(*self, other)
+    }
 }
 impl Integer<i16> for i16 {
     const MIN: i16 = i16::MIN;
@@ -54,6 +74,22 @@ impl Integer<i16> for i16 {
     fn from_i32(value: i32) -> i16 {
         value as i16
     }
+
+    fn from_f64(value: f64) -> i16 {
+        value as i16
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+
+    fn saturating_add(&self, other: i16) -> i16 {
+        i16::saturating_add(*self, other)
+    }
+
+    fn saturating_sub(&self, other: i16) -> i16 {
+        i16::saturating_sub(*self, other)
+    }
 }
 impl Integer<i32> for i32 {
     const MIN: i32 = i32::MIN;
@@ -62,6 +98,22 @@ impl Integer<i32> for i32 {
     fn from_i32(value: i32) -> i32 {
         value
     }
+
+    fn from_f64(value: f64) -> i32 {
+        value as i32
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+
+    fn saturating_add(&self, other: i32) -> i32 {
+        i32::saturating_add(*self, other)
+    }
+
+    fn saturating_sub(&self, other: i32) -> i32 {
+        i32::saturating_sub(*self, other)
+    }
 }
 impl Integer<i64> for i64 {
     const MIN: i64 = i64::MIN;
@@ -70,6 +122,22 @@ impl Integer<i64> for i64 {
     fn from_i32(value: i32) -> i64 {
         value as i64
     }
+
+    fn from_f64(value: f64) -> i64 {
+        value as i64
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+
+    fn saturating_add(&self, other: i64) -> i64 {
+        i64::saturating_add(*self, other)
+    }
+
+    fn saturating_sub(&self, other: i64) -> i64 {
+        i64::saturating_sub(*self, other)
+    }
 }
 impl Integer<i128> for i128 {
     const MIN: i128 = i128::MIN;
@@ -78,4 +146,20 @@ impl Integer<i128> for i128 {
     fn from_i32(value: i32) -> i128 {
         value as i128
     }
+
+    fn from_f64(value: f64) -> i128 {
+        value as i128
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+
+    fn saturating_add(&self, other: i128) -> i128 {
+        i128::saturating_add(*self, other)
+    }
+
+    fn saturating_sub(&self, other: i128) -> i128 {
+        i128::saturating_sub(*self, other)
+    }
 }
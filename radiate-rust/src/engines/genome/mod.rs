@@ -3,9 +3,11 @@ pub mod genes;
 pub mod genotype;
 pub mod phenotype;
 pub mod population;
+pub mod species;
 
 pub use chromosome::*;
 pub use genes::*;
 pub use genotype::*;
 pub use phenotype::*;
 pub use population::*;
+pub use species::*;
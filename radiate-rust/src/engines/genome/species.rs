@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use crate::engines::genome::genes::gene::Gene;
+use crate::engines::genome::genotype::Genotype;
+use crate::engines::score::Score;
+
+pub type DistanceFn<G, A> = Arc<dyn Fn(&Genotype<G, A>, &Genotype<G, A>) -> f32>;
+
+pub struct Speciation<G, A>
+where
+    G: Gene<G, A>,
+{
+    pub distance_fn: DistanceFn<G, A>,
+    pub threshold: f32,
+    pub target_species: usize,
+    pub threshold_step: f32,
+    pub min_threshold: f32,
+    pub max_stagnation: i32,
+}
+
+impl<G, A> Speciation<G, A>
+where
+    G: Gene<G, A>,
+{
+    pub fn new(distance_fn: impl Fn(&Genotype<G, A>, &Genotype<G, A>) -> f32 + 'static) -> Self {
+        Speciation {
+            distance_fn: Arc::new(distance_fn),
+            threshold: 3.0,
+            target_species: 10,
+            threshold_step: 0.3,
+            min_threshold: 0.1,
+            max_stagnation: 15,
+        }
+    }
+
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn target_species(mut self, target_species: usize, threshold_step: f32) -> Self {
+        self.target_species = target_species;
+        self.threshold_step = threshold_step;
+        self
+    }
+
+    pub fn min_threshold(mut self, min_threshold: f32) -> Self {
+        self.min_threshold = min_threshold;
+        self
+    }
+
+    pub fn max_stagnation(mut self, max_stagnation: i32) -> Self {
+        self.max_stagnation = max_stagnation;
+        self
+    }
+
+    pub fn distance(&self, one: &Genotype<G, A>, two: &Genotype<G, A>) -> f32 {
+        (self.distance_fn)(one, two)
+    }
+
+    pub fn adjust_threshold(&self, threshold: f32, species_count: usize) -> f32 {
+        if self.target_species == 0 {
+            return threshold;
+        }
+
+        let adjusted = match species_count.cmp(&self.target_species) {
+            std::cmp::Ordering::Less => threshold - self.threshold_step,
+            std::cmp::Ordering::Greater => threshold + self.threshold_step,
+            std::cmp::Ordering::Equal => threshold,
+        };
+
+        adjusted.max(self.min_threshold)
+    }
+}
+
+pub struct Species<G, A>
+where
+    G: Gene<G, A>,
+{
+    pub id: usize,
+    pub born: i32,
+    pub representative: Genotype<G, A>,
+    pub members: Vec<usize>,
+    pub shared_fitness: Vec<f32>,
+    pub best_score: Option<Score>,
+    pub mean_score: f32,
+    pub adjusted_fitness: f32,
+    pub offspring: usize,
+    pub stagnation: i32,
+}
+
+impl<G, A> Species<G, A>
+where
+    G: Gene<G, A>,
+{
+    pub fn new(id: usize, representative: Genotype<G, A>, generation: i32) -> Self {
+        Species {
+            id,
+            born: generation,
+            representative,
+            members: Vec::new(),
+            shared_fitness: Vec::new(),
+            best_score: None,
+            mean_score: 0.0,
+            adjusted_fitness: 0.0,
+            offspring: 0,
+            stagnation: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn age(&self, generation: i32) -> i32 {
+        generation - self.born
+    }
+}
+
+impl<G, A> Clone for Species<G, A>
+where
+    G: Gene<G, A>,
+{
+    fn clone(&self) -> Self {
+        Species {
+            id: self.id,
+            born: self.born,
+            representative: self.representative.clone(),
+            members: self.members.clone(),
+            shared_fitness: self.shared_fitness.clone(),
+            best_score: self.best_score.clone(),
+            mean_score: self.mean_score,
+            adjusted_fitness: self.adjusted_fitness,
+            offspring: self.offspring,
+            stagnation: self.stagnation,
+        }
+    }
+}
+
+impl<G, A> std::fmt::Debug for Species<G, A>
+where
+    G: Gene<G, A>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Species {{ id: {}, size: {}, best: {:?}, mean: {:.4}, adjusted: {:.4}, offspring: {}, stagnation: {} }}",
+            self.id,
+            self.members.len(),
+            self.best_score.as_ref().map(|score| score.as_float()),
+            self.mean_score,
+            self.adjusted_fitness,
+            self.offspring,
+            self.stagnation
+        )
+    }
+}
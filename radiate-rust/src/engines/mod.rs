@@ -1,5 +1,8 @@
 pub mod alterers;
 pub mod codexes;
+pub mod competition;
+pub mod cooperative_engine;
+pub mod cooperative_engine_params;
 pub mod domain;
 pub mod engine_context;
 pub mod genetic_engine;
@@ -12,6 +15,9 @@ pub mod selectors;
 
 pub use alterers::*;
 pub use codexes::*;
+pub use competition::*;
+pub use cooperative_engine::*;
+pub use cooperative_engine_params::*;
 pub use domain::*;
 pub use engine_context::*;
 pub use genetic_engine::*;
@@ -1,5 +1,7 @@
 use rand::{rngs::ThreadRng, Rng};
 
+const SMALL_SUBSET_SIZE: usize = 8;
+
 pub fn individual_indexes(
     random: &mut ThreadRng,
     index: usize,
@@ -27,6 +29,17 @@ pub fn subset(n: usize, k: usize, random: &mut ThreadRng) -> Vec<i32> {
         panic!("n smaller than k: {} < {}.", n, k);
     }
 
+    // build_subset draws from empty ranges when n is tiny (species can shrink to
+    // a handful of members), so small populations are sampled directly.
+    if n < SMALL_SUBSET_SIZE {
+        let mut sub = rand::seq::index::sample(random, n, k)
+            .into_iter()
+            .map(|x| x as i32)
+            .collect::<Vec<i32>>();
+        sub.sort();
+        return sub;
+    }
+
     let mut sub = vec![0; k as usize];
     next(n as i32, &mut sub, random);
     sub
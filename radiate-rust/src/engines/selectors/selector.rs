@@ -76,7 +76,10 @@ where
                         None => 0.0,
                     };
 
-                    fitness_values.push(score / total);
+                    fitness_values.push(match total > 0.0 {
+                        true => score / total,
+                        false => 1.0 / population.len() as f32,
+                    });
                 }
 
                 if optimize == &Optimize::Minimize {
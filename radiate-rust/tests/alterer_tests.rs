@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod tests {
+
+    use radiate_rust::*;
+
+    fn float_chromosomes(
+        min: f32,
+        max: f32,
+    ) -> (Chromosome<FloatGene, f32>, Chromosome<FloatGene, f32>) {
+        let codex = FloatCodex::new(1, 50, min, max).with_bounds(min, max);
+
+        let one = codex.encode().get_chromosome(0).clone();
+        let two = codex.encode().get_chromosome(0).clone();
+
+        (one, two)
+    }
+
+    #[test]
+    fn real_coded_crossovers_stay_in_bounds() {
+        let crossovers: Vec<Box<dyn Crossover<FloatGene, f32>>> = vec![
+            Box::new(SimulatedBinaryCrossover::new(1.0, 2.0)),
+            Box::new(BlendCrossover::new(1.0, 0.5)),
+            Box::new(ArithmeticCrossover::new(1.0)),
+            Box::new(SingleArithmeticCrossover::new(1.0)),
+            Box::new(LineCrossover::new(1.0, 0.5)),
+        ];
+
+        for crossover in crossovers.iter() {
+            let (mut one, mut two) = float_chromosomes(-1.0, 1.0);
+
+            for _ in 0..100 {
+                crossover.cross_chromosomes(&mut one, &mut two);
+            }
+
+            assert!(one.is_valid());
+            assert!(two.is_valid());
+        }
+    }
+
+    #[test]
+    fn real_coded_crossovers_gate_genes_on_rate() {
+        let crossovers = |rate: f32| -> Vec<Box<dyn Crossover<FloatGene, f32>>> {
+            vec![
+                Box::new(SimulatedBinaryCrossover::new(rate, 2.0)),
+                Box::new(BlendCrossover::new(rate, 0.5)),
+                Box::new(ArithmeticCrossover::new(rate)),
+                Box::new(LineCrossover::new(rate, 0.5)),
+            ]
+        };
+
+        for crossover in crossovers(0.0).iter() {
+            let (mut one, mut two) = float_chromosomes(-1.0, 1.0);
+            let (before_one, before_two) = (one.clone(), two.clone());
+
+            assert_eq!(crossover.cross_chromosomes(&mut one, &mut two), 0);
+            assert!(one == before_one && two == before_two);
+        }
+
+        for crossover in crossovers(0.5).iter() {
+            let (mut one, mut two) = float_chromosomes(-1.0, 1.0);
+
+            let count = crossover.cross_chromosomes(&mut one, &mut two);
+            assert!(count > 5 && count < 45);
+        }
+    }
+
+    #[test]
+    fn arithmetic_crossover_preserves_gene_sum() {
+        let (mut one, mut two) = float_chromosomes(0.0, 10.0);
+        let sum_before = one
+            .iter()
+            .chain(two.iter())
+            .map(|gene| gene.allele)
+            .sum::<f32>();
+
+        ArithmeticCrossover::new(1.0).cross_chromosomes(&mut one, &mut two);
+
+        let sum_after = one
+            .iter()
+            .chain(two.iter())
+            .map(|gene| gene.allele)
+            .sum::<f32>();
+        assert!((sum_before - sum_after).abs() < 1e-2);
+    }
+
+    #[test]
+    fn numeric_mutators_stay_in_bounds() {
+        let mutators: Vec<Box<dyn Mutate<IntGene<i32>, i32>>> = vec![
+            Box::new(GaussianMutator::scaled(1.0, 0.5)),
+            Box::new(CauchyMutator::new(1.0, 10.0)),
+            Box::new(PolynomialMutator::new(1.0, 5.0)),
+            Box::new(CreepMutator::new(1.0, 3)),
+        ];
+
+        let codex = IntCodex::new(1, 50, -10, 10).with_bounds(-10, 10);
+        for mutator in mutators.iter() {
+            let mut chromosome = codex.encode().get_chromosome(0).clone();
+
+            for _ in 0..100 {
+                mutator.mutate_chromosome(&mut chromosome, 0);
+            }
+
+            assert!(chromosome.is_valid());
+        }
+
+        let float_codex = FloatCodex::new(1, 50, 0.0, 1.0);
+        let mut chromosome = float_codex.encode().get_chromosome(0).clone();
+        for _ in 0..100 {
+            GaussianMutator::new(1.0, 2.0).mutate_chromosome(&mut chromosome, 0);
+        }
+
+        assert!(chromosome.is_valid());
+    }
+
+    #[test]
+    fn numeric_mutators_only_touch_genes_within_range() {
+        let mutators: Vec<Box<dyn Mutate<IntGene<i32>, i32>>> = vec![
+            Box::new(GaussianMutator::scaled(1.0, 0.5)),
+            Box::new(CauchyMutator::new(1.0, 10.0)),
+            Box::new(PolynomialMutator::new(1.0, 5.0)),
+            Box::new(CreepMutator::new(1.0, 3)),
+        ];
+
+        let codex = IntCodex::new(1, 50, -10, 10);
+        for mutator in mutators.iter() {
+            let mut chromosome = codex.encode().get_chromosome(0).clone();
+            let original = chromosome.clone();
+
+            assert_eq!(mutator.mutate_chromosome(&mut chromosome, i32::MIN), 0);
+            assert!(chromosome == original);
+
+            let count = mutator.mutate_chromosome(&mut chromosome, 0);
+            assert!(count > 5 && count < 45);
+        }
+    }
+
+    #[test]
+    fn creep_mutator_moves_by_at_most_step() {
+        let codex = IntCodex::<i32>::new(1, 50, -1000, 1000);
+        let original = codex.encode().get_chromosome(0).clone();
+
+        let mut mutated = original.clone();
+        CreepMutator::new(1.0, 2).mutate_chromosome(&mut mutated, i32::MAX);
+
+        for (before, after) in original.iter().zip(mutated.iter()) {
+            let diff = (before.allele() - after.allele()).abs();
+            assert!(diff <= 2);
+        }
+    }
+
+    #[test]
+    fn creep_mutator_steps_large_integers_exactly() {
+        let start = (1_i64 << 40) + 1;
+        let codex = IntCodex::<i64>::new(1, 50, start, start + 1000);
+        let original = codex.encode().get_chromosome(0).clone();
+
+        let mut mutated = original.clone();
+        CreepMutator::new(1.0, 1).mutate_chromosome(&mut mutated, i32::MAX);
+
+        for (before, after) in original.iter().zip(mutated.iter()) {
+            let diff = (before.allele() - after.allele()).abs();
+            assert!(diff <= 1);
+            assert!(diff == 1 || *after.allele() == start || *after.allele() == start + 1000);
+        }
+    }
+
+    #[test]
+    fn segment_alterers_regrow_empty_chromosomes() {
+        let codex = VariableCodex::new(1, 0, 10, std::sync::Arc::new(|| FloatGene::new(0.0, 1.0)));
+
+        let growers: Vec<Box<dyn Mutate<FloatGene, f32>>> = vec![
+            Box::new(InsertionMutator::new(1.0, 4)),
+            Box::new(DuplicationMutator::new(1.0, 4)),
+        ];
+
+        for grower in growers.iter() {
+            let mut chromosome = codex.encode().get_chromosome(0).clone();
+            while chromosome.len() > 0 {
+                DeletionMutator::new(1.0, 10).mutate_chromosome(&mut chromosome, 0);
+            }
+
+            assert!(grower.mutate_chromosome(&mut chromosome, 0) > 0);
+            assert!(chromosome.len() > 0 && chromosome.is_valid());
+        }
+    }
+
+    #[test]
+    fn segment_alterers_respect_length_bounds() {
+        let codex = VariableCodex::new(1, 5, 20, std::sync::Arc::new(|| FloatGene::new(0.0, 1.0)));
+
+        let mutators: Vec<Box<dyn Mutate<FloatGene, f32>>> = vec![
+            Box::new(InsertionMutator::new(1.0, 4)),
+            Box::new(DeletionMutator::new(1.0, 4)),
+            Box::new(DuplicationMutator::new(1.0, 4)),
+        ];
+
+        for mutator in mutators.iter() {
+            let mut chromosome = codex.encode().get_chromosome(0).clone();
+
+            for _ in 0..100 {
+                mutator.mutate_chromosome(&mut chromosome, 0);
+                assert!(chromosome.len() >= 5 && chromosome.len() <= 20);
+            }
+        }
+
+        let crossover = CutAndSpliceCrossover::new(1.0);
+        for _ in 0..100 {
+            let mut one = codex.encode().get_chromosome(0).clone();
+            let mut two = codex.encode().get_chromosome(0).clone();
+            let total = one.len() + two.len();
+
+            crossover.cross_chromosomes(&mut one, &mut two);
+
+            assert_eq!(one.len() + two.len(), total);
+            assert!(one.is_valid() && two.is_valid());
+        }
+    }
+
+    #[test]
+    fn subset_samples_small_populations() {
+        let mut random = rand::thread_rng();
+
+        for n in 2..64 {
+            let orders = match n < 8 {
+                true => n,
+                false => 2,
+            };
+
+            for k in 1..=orders {
+                for _ in 0..50 {
+                    let sub = subset(n, k, &mut random);
+
+                    assert_eq!(sub.len(), k);
+                    assert!(sub.windows(2).all(|pair| pair[0] < pair[1]));
+                    assert!(sub.iter().all(|idx| *idx >= 0 && (*idx as usize) < n));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn crossover_skips_single_member_populations() {
+        let codex = FloatCodex::new(1, 10, 0.0, 1.0);
+        let alterer = CompositeAlterer::new(vec![
+            Alterer::UniformCrossover(1.0),
+            Alterer::MultiPointCrossover(1.0, 2),
+        ]);
+
+        let mut population =
+            Population::from_vec(vec![Phenotype::from_genotype(codex.encode(), 0)]);
+        let before = population.get(0).genotype().clone();
+
+        alterer.alter(&mut population, &Optimize::Maximize, 1);
+
+        assert_eq!(population.len(), 1);
+        assert!(*population.get(0).genotype() == before);
+    }
+}
@@ -0,0 +1,364 @@
+#[cfg(test)]
+mod tests {
+
+    use radiate_rust::*;
+
+    #[test]
+    fn codexes_pass_bounds_to_genes_in_order() {
+        let float_codex = FloatCodex::new(1, 5, -10.0, 10.0).with_bounds(-2.0, 3.0);
+        for gene in float_codex.encode().get_chromosome(0).iter() {
+            assert_eq!(gene.limits(), (-2.0, 3.0));
+        }
+
+        let int_codex = IntCodex::<i32>::new(1, 5, -10, 10).with_bounds(-2, 3);
+        for gene in int_codex.encode().get_chromosome(0).iter() {
+            assert_eq!(gene.limits(), (-2.0, 3.0));
+        }
+    }
+
+    #[test]
+    fn bound_repair_keeps_genes_in_range() {
+        let gene = FloatGene::new(0.0, 1.0);
+
+        let reflected = BoundRepair::Reflect
+            .apply(&gene.from_allele(&1.25))
+            .unwrap();
+        let wrapped = BoundRepair::Wrap.apply(&gene.from_allele(&1.25)).unwrap();
+        let clamped = BoundRepair::Clamp.apply(&gene.from_allele(&-3.0)).unwrap();
+
+        assert!((reflected.allele - 0.75).abs() < 1e-5);
+        assert!((wrapped.allele - 0.25).abs() < 1e-5);
+        assert_eq!(clamped.allele, 0.0);
+        assert!(BoundRepair::Discard.apply(&gene).is_none());
+    }
+
+    #[test]
+    fn engine_repairs_invalid_genes() {
+        let codex = FloatCodex::new(1, 5, 0.0, 1.0);
+
+        let engine = GeneticEngine::from_codex(&codex)
+            .population_size(20)
+            .repair(BoundRepair::Reflect)
+            .alterer(vec![Alterer::mutation(NumericMutator::new(0.5))])
+            .fitness_fn(|genotype: Vec<Vec<f32>>| Score::from_f32(genotype[0].iter().sum()))
+            .build();
+
+        let result = engine.run(|output| output.index == 10);
+
+        assert!(result
+            .population
+            .iter()
+            .all(|phenotype| phenotype.genotype().is_valid()));
+    }
+
+    #[test]
+    fn engine_applies_repair_fn() {
+        let codex = IntCodex::<i32>::new(1, 4, 0, 10);
+
+        let engine = GeneticEngine::from_codex(&codex)
+            .population_size(20)
+            .repair_fn(|genotype: &mut Genotype<IntGene<i32>, i32>| {
+                let chromosome = genotype.get_chromosome_mut(0);
+                let first = chromosome.get_gene(0).from_allele(&0);
+                chromosome.set_gene(0, first);
+                true
+            })
+            .fitness_fn(|genotype: Vec<Vec<i32>>| Score::from_int(genotype[0].iter().sum()))
+            .build();
+
+        let result = engine.run(|output| output.index == 5);
+
+        assert!(result
+            .population
+            .iter()
+            .filter(|phenotype| phenotype.generation > 0)
+            .all(|phenotype| *phenotype.genotype().get_chromosome(0).get_gene(0).allele() == 0));
+    }
+
+    #[test]
+    fn engine_rescores_population_when_refreshed() {
+        let codex = FloatCodex::new(1, 5, 0.0, 1.0);
+        let generation = std::rc::Rc::new(std::cell::Cell::new(-1));
+
+        let refreshed = generation.clone();
+        let scored = generation.clone();
+        let engine = GeneticEngine::from_codex(&codex)
+            .population_size(20)
+            .minimizing()
+            .refresh_fn(move |index| {
+                refreshed.set(index);
+                true
+            })
+            .fitness_fn(move |_: Vec<Vec<f32>>| Score::from_int(scored.get()))
+            .build();
+
+        let result = engine.run(|output| output.index == 5);
+
+        assert_eq!(generation.get(), 4);
+        assert!(*result.score() == Score::from_int(4));
+        assert!(result
+            .population
+            .iter()
+            .all(|phenotype| *phenotype.score() == Some(Score::from_int(4))));
+    }
+
+    #[test]
+    fn engine_speciates_and_allots_offspring() {
+        let codex = FloatCodex::new(1, 3, 0.0, 1.0);
+
+        let engine = GeneticEngine::from_codex(&codex)
+            .population_size(40)
+            .speciation(
+                Speciation::new(
+                    |one: &Genotype<FloatGene, f32>, two: &Genotype<FloatGene, f32>| {
+                        one.get_chromosome(0)
+                            .iter()
+                            .zip(two.get_chromosome(0).iter())
+                            .map(|(a, b)| (a.allele - b.allele).abs())
+                            .sum::<f32>()
+                    },
+                )
+                .threshold(0.5)
+                .target_species(4, 0.05),
+            )
+            .fitness_fn(|genotype: Vec<Vec<f32>>| Score::from_f32(genotype[0].iter().sum()))
+            .build();
+
+        let result = engine.run(|output| output.index == 15);
+
+        assert_eq!(result.population.len(), 40);
+        assert!(!result.species.is_empty());
+        assert_eq!(
+            result
+                .species
+                .iter()
+                .map(|species| species.offspring)
+                .sum::<usize>(),
+            32
+        );
+        assert!(result
+            .species
+            .iter()
+            .all(|species| species.adjusted_fitness >= 0.0));
+        assert!(result.species.iter().any(|species| !species.is_empty()));
+    }
+
+    #[test]
+    fn engine_species_members_index_the_bred_population() {
+        let codex = FloatCodex::new(1, 3, 0.0, 1.0);
+        let distance = |one: &Genotype<FloatGene, f32>, two: &Genotype<FloatGene, f32>| {
+            one.get_chromosome(0)
+                .iter()
+                .zip(two.get_chromosome(0).iter())
+                .map(|(a, b)| (a.allele - b.allele).abs())
+                .sum::<f32>()
+        };
+        let fitness_fn = |genotype: Vec<Vec<f32>>| Score::from_f32(genotype[0].iter().sum());
+
+        for selector in [Selector::Tournament(3), Selector::Roulette] {
+            let engine = GeneticEngine::from_codex(&codex)
+                .population_size(40)
+                .offspring_selector(selector)
+                .minimizing()
+                .speciation(
+                    Speciation::new(distance)
+                        .threshold(0.5)
+                        .target_species(4, 0.05),
+                )
+                .fitness_fn(fitness_fn)
+                .build();
+
+            let result = engine.run(|output| output.index == 10);
+
+            let mut members = result
+                .species
+                .iter()
+                .flat_map(|species| species.members.iter().cloned())
+                .collect::<Vec<usize>>();
+            members.sort();
+            assert_eq!(members, (0..40).collect::<Vec<usize>>());
+
+            for species in result.species.iter() {
+                for idx in species.members.iter() {
+                    let member = result.population.get(*idx);
+                    assert!(
+                        distance(&species.representative, member.genotype())
+                            < result.species_threshold
+                    );
+                    assert!(*member.score() == Some(fitness_fn(codex.decode(member.genotype()))));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn engine_scores_individuals_by_competing() {
+        let play = |one: &Vec<Vec<f32>>, two: &Vec<Vec<f32>>| match one[0][0] > two[0][0] {
+            true => (1.0, 0.0),
+            false => (0.0, 1.0),
+        };
+
+        for matchup in [
+            Matchup::RoundRobin,
+            Matchup::RandomOpponents(5),
+            Matchup::HallOfFame(3),
+            Matchup::HostParasite(5),
+        ] {
+            let codex = FloatCodex::new(1, 1, 0.0, 1.0);
+            let engine = GeneticEngine::from_codex(&codex)
+                .population_size(30)
+                .max_age(50)
+                .survivor_selector(Selector::Elitism)
+                .competition(Competition::new(matchup, play).hall_of_fame_size(4))
+                .build();
+
+            let result = engine.run(|output| output.index == 25);
+
+            assert_eq!(result.population.len(), 30);
+            assert!(result.best[0][0] > 0.8);
+            assert!(result.population.iter().all(|individual| {
+                individual
+                    .score()
+                    .as_ref()
+                    .is_some_and(|score| (0.0..=1.0).contains(&score.as_float()))
+            }));
+
+            match matchup {
+                Matchup::HallOfFame(_) => {
+                    assert_eq!(result.hall_of_fame.len(), 4);
+                    assert_eq!(result.opponents.len(), 0);
+                }
+                Matchup::HostParasite(_) => {
+                    assert_eq!(result.opponents.len(), 30);
+                    assert!(result
+                        .opponents
+                        .iter()
+                        .all(|individual| individual.score().is_some()));
+                }
+                _ => assert!(result.hall_of_fame.is_empty()),
+            }
+        }
+    }
+
+    type FloatContext = EngineContext<FloatGene, f32, Vec<Vec<f32>>>;
+    type IntContext = EngineContext<IntGene<i32>, i32, Vec<Vec<i32>>>;
+
+    #[test]
+    fn cooperative_engine_evolves_populations_together() {
+        for collaborator in [Collaborator::Best, Collaborator::Random, Collaborator::Both] {
+            let float_codex = FloatCodex::new(1, 2, 0.0, 1.0);
+            let int_codex = IntCodex::<i32>::new(1, 3, 0, 10);
+
+            let engine = CooperativeEngine::from_fn(|team: &Team| {
+                let floats = team.get::<Vec<Vec<f32>>>(0)[0].iter().sum::<f32>();
+                let ints = team.get::<Vec<Vec<i32>>>(1)[0].iter().sum::<i32>() as f32;
+                Score::from_f32(floats + ints)
+            })
+            .member(
+                GeneticEngine::from_codex(&float_codex)
+                    .population_size(40)
+                    .offspring_selector(Selector::Tournament(3)),
+            )
+            .member(
+                GeneticEngine::from_codex(&int_codex)
+                    .population_size(30)
+                    .offspring_selector(Selector::Tournament(3))
+                    .alterer(vec![Alterer::Mutator(0.1), Alterer::UniformCrossover(0.5)]),
+            )
+            .collaborator(collaborator)
+            .minimizing()
+            .build();
+
+            assert_eq!(engine.members.len(), 2);
+            for member in engine.members.iter() {
+                assert_eq!(*member.optimize(), Optimize::Minimize);
+            }
+
+            let result = engine.run(|ctx| ctx.index == 40);
+            let one = result.get::<FloatContext>(0);
+            let two = result.get::<IntContext>(1);
+
+            assert_eq!(one.index, 40);
+            assert_eq!(two.index, 40);
+            assert_eq!(one.population.len(), 40);
+            assert_eq!(two.population.len(), 30);
+            assert!(one.best[0].iter().sum::<f32>() < 1.0);
+            assert!(two.best[0].iter().sum::<i32>() <= 10);
+        }
+    }
+
+    #[test]
+    fn cooperative_engine_rescores_against_current_collaborators() {
+        let float_codex = FloatCodex::new(1, 4, 0.0, 1.0);
+        let int_codex = IntCodex::<i32>::new(1, 4, 0, 10);
+        let team_fn = |floats: &Vec<Vec<f32>>, ints: &Vec<Vec<i32>>| {
+            Score::from_f32(floats[0].iter().sum::<f32>() * ints[0].iter().sum::<i32>() as f32)
+        };
+
+        for generations in 1..8 {
+            let engine =
+                CooperativeEngine::from_fn(move |team: &Team| team_fn(team.get(0), team.get(1)))
+                    .member(GeneticEngine::from_codex(&float_codex).population_size(20))
+                    .member(GeneticEngine::from_codex(&int_codex).population_size(20))
+                    .collaborator(Collaborator::Best)
+                    .build();
+
+            let result = engine.run(|ctx| ctx.index == generations);
+            let one = result.get::<FloatContext>(0);
+            let two = result.get::<IntContext>(1);
+
+            let partner = float_codex.decode(one.population.get(0).genotype());
+            for individual in two.population.iter() {
+                let ints = int_codex.decode(individual.genotype());
+                assert!(*individual.score() == Some(team_fn(&partner, &ints)));
+            }
+        }
+    }
+
+    #[test]
+    fn cooperative_engine_combines_more_than_two_populations() {
+        let float_codex = FloatCodex::new(1, 2, 0.0, 1.0);
+        let int_codex = IntCodex::<i32>::new(1, 3, 0, 10);
+        let target_codex = FloatCodex::new(1, 1, -5.0, 5.0);
+
+        let engine = CooperativeEngine::from_fn(|team: &Team| {
+            let floats = team.get::<Vec<Vec<f32>>>(0)[0].iter().sum::<f32>();
+            let ints = team.get::<Vec<Vec<i32>>>(1)[0].iter().sum::<i32>() as f32;
+            let target = team.get::<Vec<Vec<f32>>>(2)[0][0];
+            Score::from_f32((floats + ints + target - 3.0).abs())
+        })
+        .member(GeneticEngine::from_codex(&float_codex).population_size(30))
+        .member(GeneticEngine::from_codex(&int_codex).population_size(30))
+        .member(GeneticEngine::from_codex(&target_codex).population_size(30))
+        .collaborator(Collaborator::Both)
+        .minimizing()
+        .build();
+
+        let result = engine.run(|ctx| ctx.index == 50);
+        let floats = &result.get::<FloatContext>(0).best;
+        let ints = &result.get::<IntContext>(1).best;
+        let target = result.get::<FloatContext>(2);
+
+        assert_eq!(result.contexts.len(), 3);
+        assert_eq!(target.population.len(), 30);
+
+        let total =
+            floats[0].iter().sum::<f32>() + ints[0].iter().sum::<i32>() as f32 + target.best[0][0];
+        assert!((total - 3.0).abs() < 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not decode to the requested type")]
+    fn cooperative_team_rejects_the_wrong_member_type() {
+        let float_codex = FloatCodex::new(1, 1, 0.0, 1.0);
+        let int_codex = IntCodex::<i32>::new(1, 1, 0, 10);
+
+        CooperativeEngine::from_fn(|team: &Team| {
+            Score::from_f32(team.get::<Vec<Vec<f32>>>(1)[0][0])
+        })
+        .member(GeneticEngine::from_codex(&float_codex).population_size(10))
+        .member(GeneticEngine::from_codex(&int_codex).population_size(10))
+        .build()
+        .run(|ctx| ctx.index == 1);
+    }
+}